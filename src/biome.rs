@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use std::fmt::{self, Display};
+
+use crate::{pascalize, resource::*, titleize, Modifiers, Recipe, Resource};
+
+pub fn all_biomes() -> Vec<Biome> {
+    vec![
+        Biome::RoyalWoodlands,
+        Biome::CoralForest,
+        Biome::ScarletOrchard,
+        Biome::CursedRoyalWoodlands,
+        Biome::Marshlands,
+        Biome::SealedForest,
+        Biome::CoastalGrove,
+        Biome::AshenThicket,
+    ]
+}
+
+/// Raw resources that come from the land, the biome decides how easy they are to find.
+pub fn natural_resources() -> Vec<Resource> {
+    vec![
+        wood(),
+        mushrooms(),
+        roots(),
+        vegetables(),
+        fish(),
+        meat(),
+        eggs(),
+        insects(),
+        berries(),
+        grain(),
+        herbs(),
+        reed(),
+        resin(),
+        plant_fiber(),
+        algae(),
+        scales(),
+        clay(),
+        stone(),
+        salt(),
+        copper_ore(),
+        sea_marrow(),
+    ]
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Biome {
+    RoyalWoodlands,
+    CoralForest,
+    ScarletOrchard,
+    CursedRoyalWoodlands,
+    Marshlands,
+    SealedForest,
+    CoastalGrove,
+    AshenThicket,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Rarity {
+    Common,
+    Rare,
+    Impossible,
+}
+
+impl Biome {
+    fn rare(&self) -> Vec<Resource> {
+        match self {
+            Self::RoyalWoodlands => vec![copper_ore(), salt(), scales(), fish()],
+            Self::CoralForest => vec![clay(), copper_ore(), meat()],
+            Self::ScarletOrchard => vec![fish(), scales(), algae()],
+            Self::CursedRoyalWoodlands => vec![copper_ore(), salt(), scales(), fish(), meat()],
+            Self::Marshlands => vec![stone(), clay(), copper_ore()],
+            Self::SealedForest => vec![salt(), fish(), algae()],
+            Self::CoastalGrove => vec![copper_ore(), stone()],
+            Self::AshenThicket => vec![fish(), algae(), mushrooms()],
+        }
+    }
+
+    fn impossible(&self) -> Vec<Resource> {
+        match self {
+            Self::CoralForest | Self::CoastalGrove => vec![],
+            Self::RoyalWoodlands | Self::CursedRoyalWoodlands => vec![sea_marrow(), algae()],
+            Self::AshenThicket => vec![sea_marrow(), scales()],
+            Self::ScarletOrchard | Self::Marshlands | Self::SealedForest => vec![sea_marrow()],
+        }
+    }
+
+    /// How easy a raw resource is to come by here, `None` for goods that aren't found in the wild.
+    pub fn rarity(&self, good: Resource) -> Option<Rarity> {
+        if !natural_resources().contains(&good) {
+            None
+        } else if self.impossible().contains(&good) {
+            Some(Rarity::Impossible)
+        } else if self.rare().contains(&good) {
+            Some(Rarity::Rare)
+        } else {
+            Some(Rarity::Common)
+        }
+    }
+
+    /// The rarity of the easiest way to make something, following its recipe down to raw resources.
+    pub fn chain_rarity(&self, good: &(impl Recipe + Display), modifiers: &Modifiers) -> Rarity {
+        let natural = natural_resources()
+            .into_iter()
+            .find(|resource| resource.to_string() == good.to_string());
+        if let Some(rarity) = natural.and_then(|resource| self.rarity(resource)) {
+            return rarity;
+        }
+        modifiers
+            .recipe(good)
+            .iter()
+            .map(|ingredient_slot| {
+                ingredient_slot
+                    .iter()
+                    .map(|ingredient| self.chain_rarity(ingredient, modifiers))
+                    .min()
+                    .unwrap_or(Rarity::Common)
+            })
+            .max()
+            .unwrap_or(Rarity::Common)
+    }
+}
+
+impl TryFrom<&String> for Biome {
+    type Error = std::io::Error;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        let result = all_biomes()
+            .into_iter()
+            .find(|biome| pascalize(biome) == pascalize(value));
+
+        if let Some(biome) = result {
+            Ok(biome)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{value} is not a valid biome"),
+            ))
+        }
+    }
+}
+
+impl Display for Biome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+impl Display for Rarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
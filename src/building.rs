@@ -0,0 +1,311 @@
+use serde::{Deserialize, Serialize};
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use crate::{
+    resource::*, titleize, Housing, Modifiers, Need, Resource, Season, Service, Specialization,
+};
+
+pub fn all_buildings() -> Vec<Building> {
+    vec![
+        Building::Apothecary,
+        Building::Artisan,
+        Building::Bakery,
+        Building::Beanery,
+        Building::Brewery,
+        Building::Brickyard,
+        Building::Butcher,
+        Building::Cellar,
+        Building::Cookhouse,
+        Building::ForagersCamp,
+        Building::Furnace,
+        Building::Granary,
+        Building::HerbalistsCamp,
+        Building::Kiln,
+        Building::Leatherworker,
+        Building::LumberMill,
+        Building::Press,
+        Building::Smelter,
+        Building::Smokehouse,
+        Building::Tinctury,
+        Building::TrappersCamp,
+        Building::Weaver,
+        Building::WoodcuttersCamp,
+        Building::Workshop,
+    ]
+}
+
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Building {
+    Apothecary,
+    Artisan,
+    Bakery,
+    Beanery,
+    Brewery,
+    Brickyard,
+    Butcher,
+    Cellar,
+    Cookhouse,
+    ForagersCamp,
+    Furnace,
+    Granary,
+    HerbalistsCamp,
+    Kiln,
+    Leatherworker,
+    LumberMill,
+    Press,
+    Smelter,
+    Smokehouse,
+    Tinctury,
+    TrappersCamp,
+    Weaver,
+    WoodcuttersCamp,
+    Workshop,
+}
+
+impl Building {
+    pub fn produces(&self) -> Vec<Resource> {
+        match self {
+            Self::Apothecary => vec![tea(), dye(), incense()],
+            Self::Artisan => vec![coats(), barrels(), scrolls()],
+            Self::Bakery => vec![biscuits(), pie(), pottery()],
+            Self::Beanery => vec![porridge(), pickled_goods(), crystallized_dew()],
+            Self::Brewery => vec![ale(), porridge()],
+            Self::Brickyard => vec![bricks(), pottery(), crystallized_dew()],
+            Self::Butcher => vec![skewers(), jerky(), oil()],
+            Self::Cellar => vec![wine(), pickled_goods(), flour()],
+            Self::Cookhouse => vec![skewers(), porridge(), biscuits()],
+            Self::Furnace => vec![pie(), copper_bars(), skewers()],
+            Self::Granary => vec![fabric(), pickled_goods()],
+            Self::Kiln => vec![bricks(), coal(), jerky()],
+            Self::Leatherworker => vec![boots(), waterskins(), training_gear()],
+            Self::LumberMill => vec![planks()],
+            Self::Press => vec![oil(), flour()],
+            Self::Smelter => vec![copper_bars(), pie(), crystallized_dew()],
+            Self::Smokehouse => vec![jerky(), pottery(), incense()],
+            Self::Tinctury => vec![dye(), ale(), incense(), wine()],
+            Self::Weaver => vec![fabric(), boots()],
+            Self::Workshop => vec![planks(), fabric(), bricks()],
+            Self::ForagersCamp => vec![roots(), vegetables(), grain()],
+            Self::HerbalistsCamp => vec![herbs(), mushrooms(), berries()],
+            Self::TrappersCamp => vec![meat(), insects(), eggs()],
+            Self::WoodcuttersCamp => vec![wood()],
+        }
+    }
+
+    pub fn construction_cost(&self) -> Vec<(Resource, u32)> {
+        match self {
+            Self::ForagersCamp
+            | Self::HerbalistsCamp
+            | Self::TrappersCamp
+            | Self::WoodcuttersCamp => vec![(planks(), 2)],
+            Self::LumberMill => vec![(planks(), 8)],
+            Self::Workshop => vec![(planks(), 6), (bricks(), 2)],
+            Self::Granary | Self::Weaver => vec![(planks(), 6), (fabric(), 4)],
+            Self::Apothecary | Self::Tinctury => vec![(planks(), 6), (bricks(), 4), (fabric(), 2)],
+            Self::Bakery | Self::Brewery | Self::Cellar | Self::Cookhouse => {
+                vec![(planks(), 8), (bricks(), 6)]
+            }
+            Self::Beanery | Self::Butcher | Self::Leatherworker | Self::Press => {
+                vec![(planks(), 8), (bricks(), 4), (parts(), 2)]
+            }
+            Self::Brickyard | Self::Kiln | Self::Smokehouse => vec![(planks(), 4), (bricks(), 8)],
+            Self::Artisan | Self::Furnace | Self::Smelter => {
+                vec![(planks(), 6), (bricks(), 6), (parts(), 4)]
+            }
+        }
+    }
+
+    pub fn specialization(&self) -> Specialization {
+        match self {
+            Self::Apothecary | Self::Tinctury => Specialization::Alchemy,
+            Self::Brewery | Self::Cellar => Specialization::Brewing,
+            Self::Bakery | Self::Cookhouse => Specialization::Cooking,
+            Self::Beanery | Self::Granary | Self::Press => Specialization::Farming,
+            Self::Butcher | Self::Smokehouse => Specialization::Meat,
+            Self::Leatherworker | Self::Weaver => Specialization::Tailoring,
+            Self::Brickyard | Self::Furnace | Self::Kiln | Self::Smelter => Specialization::Warmth,
+            Self::Artisan | Self::LumberMill | Self::Workshop => Specialization::Woodworking,
+            Self::ForagersCamp
+            | Self::HerbalistsCamp
+            | Self::TrappersCamp
+            | Self::WoodcuttersCamp => Specialization::Gathering,
+        }
+    }
+
+    /// The season whose rain water powers this building's rain engines, `None` for gathering
+    /// camps, which have no engines.
+    pub fn engine_water(&self) -> Option<Season> {
+        let season = match self {
+            Self::Bakery
+            | Self::Brewery
+            | Self::Cellar
+            | Self::Cookhouse
+            | Self::Granary
+            | Self::Press => Season::Drizzle,
+            Self::Apothecary
+            | Self::Beanery
+            | Self::Leatherworker
+            | Self::LumberMill
+            | Self::Tinctury
+            | Self::Weaver
+            | Self::Workshop => Season::Clearance,
+            Self::Artisan
+            | Self::Brickyard
+            | Self::Butcher
+            | Self::Furnace
+            | Self::Kiln
+            | Self::Smelter
+            | Self::Smokehouse => Season::Storm,
+            Self::ForagersCamp
+            | Self::HerbalistsCamp
+            | Self::TrappersCamp
+            | Self::WoodcuttersCamp => return None,
+        };
+        Some(season)
+    }
+}
+
+impl Display for Building {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BuildQueue {
+    pub buildings: BTreeMap<Building, u32>,
+    pub houses: BTreeMap<Housing, u32>,
+}
+
+impl BuildQueue {
+    pub fn count(&self, building: Building) -> u32 {
+        self.buildings.get(&building).copied().unwrap_or_default()
+    }
+
+    pub fn set_count(&mut self, building: Building, count: u32) {
+        if count == 0 {
+            self.buildings.remove(&building);
+        } else {
+            self.buildings.insert(building, count);
+        }
+    }
+
+    pub fn house_count(&self, housing: Housing) -> u32 {
+        self.houses.get(&housing).copied().unwrap_or_default()
+    }
+
+    pub fn set_house_count(&mut self, housing: Housing, count: u32) {
+        if count == 0 {
+            self.houses.remove(&housing);
+        } else {
+            self.houses.insert(housing, count);
+        }
+    }
+
+    /// Everything it takes to construct the whole queue.
+    pub fn materials(&self) -> Vec<(Resource, u32)> {
+        let mut materials: Vec<(Resource, u32)> = Vec::new();
+        for (building, count) in &self.buildings {
+            for (material, amount) in building.construction_cost() {
+                match materials
+                    .iter_mut()
+                    .find(|(existing, _)| *existing == material)
+                {
+                    Some((_, total)) => *total += amount * count,
+                    None => materials.push((material, amount * count)),
+                }
+            }
+        }
+        materials
+    }
+}
+
+pub fn all_service_buildings() -> Vec<ServiceBuilding> {
+    vec![
+        ServiceBuilding::BathHouse,
+        ServiceBuilding::ClanHall,
+        ServiceBuilding::ExplorersLodge,
+        ServiceBuilding::Forum,
+        ServiceBuilding::Monastery,
+        ServiceBuilding::Tavern,
+        ServiceBuilding::Temple,
+    ]
+}
+
+#[derive(Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum ServiceBuilding {
+    BathHouse,
+    ClanHall,
+    ExplorersLodge,
+    Forum,
+    Monastery,
+    Tavern,
+    Temple,
+}
+
+impl ServiceBuilding {
+    /// The services this building provides.
+    pub fn provides(&self) -> Vec<Service> {
+        match self {
+            Self::BathHouse => vec![Service::Treatment, Service::Luxury],
+            Self::ClanHall => vec![Service::Brawling, Service::Luxury],
+            Self::ExplorersLodge => vec![Service::Education, Service::Leisure],
+            Self::Forum => vec![Service::Leisure, Service::Luxury],
+            Self::Monastery => vec![Service::Religion, Service::Treatment],
+            Self::Tavern => vec![Service::Leisure, Service::Brawling],
+            Self::Temple => vec![Service::Religion, Service::Education],
+        }
+    }
+
+    /// The services this building provides and the goods it can consume for each, as the
+    /// service recipes stand with the perks and difficulty in play.
+    pub fn services(&self, modifiers: &Modifiers) -> Vec<(Service, Vec<Resource>)> {
+        self.provides()
+            .into_iter()
+            .map(|service| (service, modifiers.goods(&Need::Service(service))))
+            .collect()
+    }
+
+    /// How many of `needs` this building can serve with the goods in `available`.
+    ///
+    /// `needs` may list a need once for every species that has it, so shared services weigh more.
+    pub fn coverage(&self, needs: &[Need], available: &[Resource], modifiers: &Modifiers) -> usize {
+        let services = self.services(modifiers);
+        needs
+            .iter()
+            .filter(|need| {
+                services.iter().any(|(service, goods)| {
+                    **need == Need::Service(*service)
+                        && goods.iter().any(|good| available.contains(good))
+                })
+            })
+            .count()
+    }
+}
+
+impl Display for ServiceBuilding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+/// Every one of `candidates` that covers at least one need, best first.
+pub fn best_service_buildings(
+    candidates: &[ServiceBuilding],
+    needs: &[Need],
+    available: &[Resource],
+    modifiers: &Modifiers,
+) -> Vec<(ServiceBuilding, usize)> {
+    let mut buildings: Vec<(ServiceBuilding, usize)> = candidates
+        .iter()
+        .copied()
+        .map(|building| (building, building.coverage(needs, available, modifiers)))
+        .filter(|(_, coverage)| *coverage > 0)
+        .collect();
+    buildings.sort_by_key(|(_, coverage)| std::cmp::Reverse(*coverage));
+    buildings
+}
@@ -0,0 +1,91 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use crate::{Modifiers, Need, Recipe, Species};
+
+#[derive(Debug)]
+pub struct Compatibility {
+    pub species: Vec<Species>,
+    /// Needs that more than one species in the lineup has.
+    pub shared_needs: usize,
+    /// Raw resources that feed the needs of more than one species.
+    pub overlapping_inputs: usize,
+    /// Every distinct need is a production chain to set up.
+    pub chains: usize,
+}
+
+impl Compatibility {
+    pub fn of(species: &[Species], modifiers: &Modifiers) -> Self {
+        let mut need_counter: BTreeMap<Need, usize> = BTreeMap::new();
+        let mut input_counter: BTreeMap<String, usize> = BTreeMap::new();
+        for one_species in species {
+            let mut inputs = BTreeSet::new();
+            for need in modifiers.needs(*one_species) {
+                *need_counter.entry(need).or_default() += 1;
+                raw_inputs(&need, &mut inputs, modifiers);
+            }
+            for input in inputs {
+                *input_counter.entry(input).or_default() += 1;
+            }
+        }
+
+        Self {
+            species: species.to_vec(),
+            shared_needs: need_counter.values().filter(|count| **count > 1).count(),
+            overlapping_inputs: input_counter.values().filter(|count| **count > 1).count(),
+            chains: need_counter.len(),
+        }
+    }
+
+    pub fn score(&self) -> isize {
+        (2 * self.shared_needs + self.overlapping_inputs) as isize - self.chains as isize
+    }
+}
+
+/// Scores every lineup of `size` species from the pool, best first.
+pub fn best_combinations(
+    pool: &[Species],
+    size: usize,
+    modifiers: &Modifiers,
+) -> Vec<Compatibility> {
+    let mut combinations: Vec<Compatibility> = combinations(pool, size)
+        .iter()
+        .map(|species| Compatibility::of(species, modifiers))
+        .collect();
+    combinations.sort_by_key(|compatibility| -compatibility.score());
+    combinations
+}
+
+fn combinations(pool: &[Species], size: usize) -> Vec<Vec<Species>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+    let Some((first, rest)) = pool.split_first() else {
+        return vec![];
+    };
+    let mut with_first: Vec<Vec<Species>> = combinations(rest, size - 1)
+        .into_iter()
+        .map(|mut combination| {
+            combination.insert(0, *first);
+            combination
+        })
+        .collect();
+    with_first.extend(combinations(rest, size));
+    with_first
+}
+
+fn raw_inputs(
+    good: &(impl Recipe + Display),
+    inputs: &mut BTreeSet<String>,
+    modifiers: &Modifiers,
+) {
+    for ingredient in modifiers.recipe(good).concat() {
+        if modifiers.recipe(&ingredient).is_empty() {
+            inputs.insert(ingredient.to_string());
+        } else {
+            raw_inputs(&ingredient, inputs, modifiers);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::{
+    io::{Error, ErrorKind},
+    path::PathBuf,
+};
+
+use crate::{Biome, Difficulty, Profile, Theme};
+
+const THEME_VAR: &str = "FOR_THE_QUEEN_THEME";
+const BIOME_VAR: &str = "FOR_THE_QUEEN_BIOME";
+const DIFFICULTY_VAR: &str = "FOR_THE_QUEEN_DIFFICULTY";
+
+/// Where the profile and run history live, `for-the-queen` in the user's config directory.
+pub fn config_dir() -> Option<PathBuf> {
+    let config_home = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .ok()?;
+    Some(config_home.join("for-the-queen"))
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Config {
+    pub theme: Theme,
+    pub biome: Option<Biome>,
+    pub difficulty: Option<Difficulty>,
+    pub profile: Profile,
+}
+
+impl Config {
+    /// Reads settings from the environment first so that command line flags can override them.
+    pub fn from_env() -> Result<Self, Error> {
+        let mut config = Self {
+            theme: Theme::detect(),
+            profile: Profile::load()?,
+            ..Self::default()
+        };
+
+        if let Ok(theme) = std::env::var(THEME_VAR) {
+            config.theme = Theme::try_from(&theme)?;
+        }
+
+        if let Ok(biome) = std::env::var(BIOME_VAR) {
+            config.biome = Some(Biome::try_from(&biome)?);
+        }
+
+        if let Ok(difficulty) = std::env::var(DIFFICULTY_VAR) {
+            config.difficulty = Some(Difficulty::try_from(&difficulty)?);
+        }
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            match flag.as_str() {
+                "--theme" => {
+                    let value = inline_value.or_else(|| args.next()).ok_or_else(|| {
+                        Error::new(ErrorKind::InvalidInput, "--theme requires a value")
+                    })?;
+                    config.theme = Theme::try_from(&value)?;
+                }
+                "--plain" => config.theme = Theme::Plain,
+                "--biome" => {
+                    let value = inline_value.or_else(|| args.next()).ok_or_else(|| {
+                        Error::new(ErrorKind::InvalidInput, "--biome requires a value")
+                    })?;
+                    config.biome = Some(Biome::try_from(&value)?);
+                }
+                "--difficulty" => {
+                    let value = inline_value.or_else(|| args.next()).ok_or_else(|| {
+                        Error::new(ErrorKind::InvalidInput, "--difficulty requires a value")
+                    })?;
+                    config.difficulty = Some(Difficulty::try_from(&value)?);
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{flag} is not a valid argument"),
+                    ))
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::{self, Display};
+
+use crate::{pascalize, titleize};
+
+pub const MAX_PRESTIGE: u8 = 20;
+
+pub fn all_difficulties() -> Vec<Difficulty> {
+    [
+        Difficulty::Settler,
+        Difficulty::Pioneer,
+        Difficulty::Veteran,
+        Difficulty::Viceroy,
+    ]
+    .into_iter()
+    .chain((1..=MAX_PRESTIGE).map(Difficulty::Prestige))
+    .collect()
+}
+
+#[derive(Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum Difficulty {
+    #[default]
+    Settler,
+    Pioneer,
+    Veteran,
+    Viceroy,
+    Prestige(u8),
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DifficultyEffect {
+    /// Villagers eat through their needs this many times as fast.
+    ConsumptionRate(f32),
+    /// Hearths burn this many times as much fuel.
+    FuelBurn(f32),
+    /// Resolve lost by every species regardless of their needs, so needs that give resolve rank
+    /// first in the plan.
+    ResolvePenalty(u32),
+    /// Blueprint drafts offer this many fewer choices, so a blueprint brought along weighs more.
+    FewerBlueprints(u32),
+}
+
+impl Difficulty {
+    pub fn effects(&self) -> Vec<DifficultyEffect> {
+        match self {
+            Self::Settler | Self::Pioneer => vec![],
+            Self::Veteran => vec![DifficultyEffect::ResolvePenalty(1)],
+            Self::Viceroy => vec![
+                DifficultyEffect::ResolvePenalty(2),
+                DifficultyEffect::FuelBurn(1.25),
+            ],
+            Self::Prestige(level) => vec![
+                DifficultyEffect::ResolvePenalty(2 + *level as u32 / 5),
+                DifficultyEffect::FuelBurn(1.25 + 0.05 * *level as f32),
+                DifficultyEffect::ConsumptionRate(1.0 + 0.05 * *level as f32),
+                DifficultyEffect::FewerBlueprints(if *level >= 2 { 1 } else { 0 }),
+            ],
+        }
+    }
+}
+
+impl TryFrom<&String> for Difficulty {
+    type Error = std::io::Error;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        let result = all_difficulties()
+            .into_iter()
+            .find(|difficulty| pascalize(difficulty) == pascalize(value));
+
+        if let Some(difficulty) = result {
+            Ok(difficulty)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{value} is not a valid difficulty"),
+            ))
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prestige(level) => write!(f, "Prestige {level}"),
+            _ => write!(f, "{}", titleize(self)),
+        }
+    }
+}
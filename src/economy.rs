@@ -1,17 +1,24 @@
-use crossterm::style::{style, Color, Stylize};
 use inquire::InquireError;
 
 use crate::{
-    all_building_materials, all_clothing, all_complex_food, all_consumable_items,
-    all_crafting_resources, all_fuel, all_services, all_simple_food, all_species, clear_screen,
-    pluralize, titleize, wood, BuildingMaterial, MultiSelectMenu, Need, Recipe, Resource, Service,
-    SingleSelectMenu, Species,
+    advise_trade, all_availabilities, all_biomes, all_building_materials, all_clothing,
+    all_complex_food, all_consumable_items, all_crafting_resources, all_difficulties,
+    all_embark_options, all_fuel, all_goals, all_goods, all_housing, all_outcomes, all_perks,
+    all_seasons, all_services, all_simple_food, all_species, best_combinations,
+    best_service_buildings, clear_screen, confirm, pause, plan_embarkation, pluralize,
+    prompt_number, prompt_text, purging_fire, titleize, wood, Availability, Biome, Blight,
+    BuildQueue, Building, Compatibility, Config, Difficulty, FuelBudget, GladeEvent, GladeEvents,
+    Goal, Inventory, Milestone, Modifiers, MultiSelectMenu, Need, Order, Orders, Perk, PlanCode,
+    Profile, RainEngines, Rarity, Recipe, Resolve, Resource, Run, RunHistory, Season, SeasonCycle,
+    Service, SingleSelectMenu, Species, Status, Theme, Timeline, Upgrade, Workforce,
+    MAX_ENGINE_LEVEL, WORKPLACES_PER_BUILDING,
 };
 
 use std::{collections::BTreeMap, fmt::Display};
 
 #[derive(Debug)]
 pub struct Economy {
+    pub name: String,
     pub species: MultiSelectMenu<Species>,
     pub services: MultiSelectMenu<Service>,
     pub fuel: MultiSelectMenu<Resource>,
@@ -22,6 +29,23 @@ pub struct Economy {
     pub complex_food: MultiSelectMenu<Resource>,
     pub clothing: MultiSelectMenu<Resource>,
     pub switcher: SingleSelectMenu<MenuKind>,
+    pub theme: Theme,
+    pub inventory: Inventory,
+    pub seasons: SeasonCycle,
+    pub engines: RainEngines,
+    pub fuel_budget: FuelBudget,
+    pub build_queue: BuildQueue,
+    pub biome: Option<Biome>,
+    pub biomes: SingleSelectMenu<Biome>,
+    pub difficulty: Option<Difficulty>,
+    pub difficulties: SingleSelectMenu<Difficulty>,
+    pub orders: Orders,
+    pub perks: MultiSelectMenu<Perk>,
+    pub glade_events: GladeEvents,
+    pub workforce: Workforce,
+    pub blight: Blight,
+    pub profile: Profile,
+    pub timeline: Timeline,
 }
 
 impl Default for Economy {
@@ -33,6 +57,7 @@ impl Default for Economy {
 impl Economy {
     pub fn new() -> Self {
         let mut economy = Self {
+            name: String::from("Settlement"),
             species: MultiSelectMenu::new("Select your species:", all_species()),
             services: MultiSelectMenu::new("Select services you can provide:", all_services()),
             fuel: MultiSelectMenu::new("Select the you can produce:", all_fuel()),
@@ -58,6 +83,26 @@ impl Economy {
             ),
             clothing: MultiSelectMenu::new("Select the clothing you can produce:", all_clothing()),
             switcher: SingleSelectMenu::new("What would you like to do?\n", all_menus()),
+            theme: Theme::default(),
+            inventory: Inventory::default(),
+            seasons: SeasonCycle::default(),
+            engines: RainEngines::default(),
+            fuel_budget: FuelBudget::default(),
+            build_queue: BuildQueue::default(),
+            biome: None,
+            biomes: SingleSelectMenu::new("Select the biome of this settlement:", all_biomes()),
+            difficulty: None,
+            difficulties: SingleSelectMenu::new(
+                "Select the difficulty you're playing on:",
+                all_difficulties(),
+            ),
+            orders: Orders::default(),
+            perks: MultiSelectMenu::new("Select the cornerstones and perks in play:", all_perks()),
+            glade_events: GladeEvents::default(),
+            workforce: Workforce::default(),
+            blight: Blight::default(),
+            profile: Profile::default(),
+            timeline: Timeline::default(),
         };
 
         economy.fuel.select(vec![wood()]);
@@ -65,20 +110,82 @@ impl Economy {
         economy
     }
 
-    pub fn plan(&mut self) -> Result<(), InquireError> {
+    pub fn configure(&mut self, config: &Config) {
+        self.theme = config.theme;
+        self.biome = config.biome.or(self.biome);
+        self.difficulty = config.difficulty.or(self.difficulty);
+        self.profile = config.profile.clone();
+        self.apply_profile();
+    }
+
+    pub fn set_profile(&mut self, profile: Profile) {
+        self.profile = profile;
+        self.apply_profile();
+    }
+
+    /// Narrows the species and menus to what the profile has unlocked.
+    fn apply_profile(&mut self) {
+        let selected = self.species.get_selections();
+        self.species = MultiSelectMenu::new("Select your species:", self.profile.species.clone());
+        self.species.select(
+            selected
+                .into_iter()
+                .filter(|species| self.profile.species.contains(species))
+                .collect(),
+        );
+        let has_engines = self.profile.has_upgrade(Upgrade::RainEngines);
+        self.switcher = SingleSelectMenu::new(
+            "What would you like to do?\n",
+            all_menus().into_iter().filter(|menu| {
+                has_engines
+                    || !matches!(menu, MenuKind::EditRainEngines | MenuKind::ViewRainEngines)
+            }),
+        );
+        if !has_engines {
+            self.engines = RainEngines::default();
+        }
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            perks: self.perks.get_selections(),
+            difficulty: self.difficulty.unwrap_or_default(),
+        }
+    }
+
+    /// One round of planning. Menus that act on the whole session are handed back to the caller.
+    pub fn step(&mut self) -> Result<Option<MenuKind>, InquireError> {
         clear_screen();
+        println!("{}", self.name);
         self.print_needs();
+        let met = self.met_needs();
+        self.timeline.update(
+            self.seasons.moment(),
+            self.selected_facets(),
+            self.species.get_selections(),
+            self.build_queue.buildings.keys().copied().collect(),
+            &met,
+        );
         if self.species.view().is_empty {
             self.edit_species()?;
+        } else if self.biome.is_none() {
+            self.edit_biome()?;
+        } else if self.difficulty.is_none() {
+            self.edit_difficulty()?;
         } else {
-            self.switch_menus()?;
+            return self.switch_menus();
         }
-        self.plan()
+        Ok(None)
     }
 
-    fn switch_menus(&mut self) -> Result<(), InquireError> {
+    fn switch_menus(&mut self) -> Result<Option<MenuKind>, InquireError> {
         let menu_kind = self.switcher.interact()?;
         match menu_kind {
+            MenuKind::NewSettlement
+            | MenuKind::SwitchSettlement
+            | MenuKind::CompareSettlements
+            | MenuKind::EditProfile
+            | MenuKind::EditTheme => return Ok(Some(menu_kind)),
             MenuKind::EditSimpleFood => self.edit_simple_food(),
             MenuKind::EditBuildingMaterials => self.edit_building_materials(),
             MenuKind::EditFuel => self.edit_fuel(),
@@ -88,7 +195,40 @@ impl Economy {
             MenuKind::EditConsumableItems => self.edit_consumable_items(),
             MenuKind::EditServices => self.edit_services(),
             MenuKind::EditSpecies => self.edit_species(),
-        }
+            MenuKind::EditBiome => self.edit_biome(),
+            MenuKind::EditDifficulty => self.edit_difficulty(),
+            MenuKind::EditAvailability => self.edit_availability(),
+            MenuKind::EditStock => self.edit_stock(),
+            MenuKind::EditConsumption => self.edit_consumption(),
+            MenuKind::EditSeason => self.edit_season(),
+            MenuKind::EditSeasonLengths => self.edit_season_lengths(),
+            MenuKind::EditRainEngines => self.edit_rain_engines(),
+            MenuKind::ViewRainEngines => self.view_rain_engines(),
+            MenuKind::EditBlight => self.edit_blight(),
+            MenuKind::ViewBlight => self.view_blight(),
+            MenuKind::EditFuelBudget => self.edit_fuel_budget(),
+            MenuKind::ViewFuelBudget => self.view_fuel_budget(),
+            MenuKind::EditBuildQueue => self.edit_build_queue(),
+            MenuKind::EditHousing => self.edit_housing(),
+            MenuKind::EditPopulation => self.edit_population(),
+            MenuKind::ViewWorkers => self.view_workers(),
+            MenuKind::PlanEmbarkation => self.plan_embarkation(),
+            MenuKind::RecommendSpecies => self.recommend_species(),
+            MenuKind::RecommendServiceBuilding => self.recommend_service_building(),
+            MenuKind::AddOrder => self.add_order(),
+            MenuKind::CompleteOrder => self.complete_order(),
+            MenuKind::VisitTrader => self.visit_trader(),
+            MenuKind::AddGladeEvent => self.add_glade_event(),
+            MenuKind::ResolveGladeEvent => self.resolve_glade_event(),
+            MenuKind::EditPerks => self.edit_perks(),
+            MenuKind::ViewForecast => self.view_forecast(),
+            MenuKind::ViewTimeline => self.view_timeline(),
+            MenuKind::SharePlan => self.share_plan(),
+            MenuKind::ImportPlan => self.import_plan(),
+            MenuKind::EndRun => self.end_run(),
+            MenuKind::ViewRunHistory => self.view_run_history(),
+        }?;
+        Ok(None)
     }
 
     fn edit_simple_food(&mut self) -> Result<(), InquireError> {
@@ -127,27 +267,985 @@ impl Economy {
         self.species.interact()
     }
 
-    fn print_needs(&self) {
-        let mut need_counter: BTreeMap<Need, usize> = BTreeMap::new();
-        let selected_species = self.species.get_selections();
-        let num_species = selected_species.len();
-        if num_species >= 1 {
-            need_counter.insert(
-                Need::BuildingMaterial(BuildingMaterial::Planks),
-                num_species,
+    fn edit_availability(&mut self) -> Result<(), InquireError> {
+        let good = SingleSelectMenu::new("Select a good:", all_goods()).interact()?;
+        let current = self.goods_menu(good).get_availability(good);
+        let title = format!(
+            "How is {good} available? (currently {})",
+            current.unwrap_or(Availability::Unavailable)
+        );
+        let availability = SingleSelectMenu::new(&title, all_availabilities()).interact()?;
+        self.goods_menu_mut(good)
+            .set_availability(good, availability);
+        Ok(())
+    }
+
+    fn edit_stock(&mut self) -> Result<(), InquireError> {
+        let good = SingleSelectMenu::new("Select a good:", all_goods()).interact()?;
+        let title = format!("How many {good} are in the warehouse?");
+        let count = prompt_number(&title, self.inventory.stock_of(good))?;
+        self.inventory.stock.insert(good, count);
+        Ok(())
+    }
+
+    fn edit_consumption(&mut self) -> Result<(), InquireError> {
+        let species =
+            SingleSelectMenu::new("Select a species:", self.species.get_selections()).interact()?;
+        let title = format!("How many goods per minute do your {species} consume for each need?");
+        let rate = prompt_number(&title, self.inventory.consumption_of(species))?;
+        self.inventory.consumption.insert(species, rate);
+        Ok(())
+    }
+
+    fn edit_season(&mut self) -> Result<(), InquireError> {
+        self.seasons.year = prompt_number("Which year is it?", self.seasons.year)?;
+        self.seasons.current =
+            SingleSelectMenu::new("Which season is it?", all_seasons()).interact()?;
+        let title = format!("How many minutes of {} have passed?", self.seasons.current);
+        self.seasons.elapsed_minutes = prompt_number(&title, 0)?;
+        Ok(())
+    }
+
+    fn edit_season_lengths(&mut self) -> Result<(), InquireError> {
+        for season in all_seasons() {
+            let title = format!("How many minutes does {season} last?");
+            let length = prompt_number(&title, self.seasons.length(season))?;
+            self.seasons.lengths.insert(season, length);
+        }
+        Ok(())
+    }
+
+    fn view_forecast(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        // during the storm what matters is lasting until it's over
+        let (minutes, deadline) = if self.seasons.current == Season::Storm {
+            (self.seasons.minutes_left(), "the storm ends")
+        } else {
+            (self.seasons.minutes_until(Season::Storm), "the storm")
+        };
+        println!("-----------------------");
+        if self.seasons.current == Season::Storm {
+            println!("Storm ends in {minutes} min");
+        } else {
+            println!("Storm in {minutes} min");
+        }
+        println!("-----------------------");
+        for forecast in self
+            .inventory
+            .forecast(&self.species.get_selections(), &self.modifiers())
+        {
+            let (status, warning) = if forecast.runs_out_before(minutes) {
+                (Status::Missing, format!(", runs out before {deadline}"))
+            } else {
+                (Status::Available, String::new())
+            };
+            let lasts = match forecast.minutes_left {
+                Some(minutes_left) => format!("lasts {minutes_left:.1} min{warning}"),
+                None => "not consumed".to_string(),
+            };
+            println!(
+                " > {}: {} in stock, {:.1}/min, {lasts}",
+                self.theme.paint(forecast.need, status),
+                forecast.stock,
+                forecast.rate
             );
-            need_counter.insert(
-                Need::BuildingMaterial(BuildingMaterial::Fabric),
-                num_species,
+        }
+        println!("-----------------------");
+        pause()
+    }
+
+    fn edit_rain_engines(&mut self) -> Result<(), InquireError> {
+        let with_engines: Vec<Building> = self
+            .profile
+            .buildings
+            .iter()
+            .copied()
+            .filter(|building| building.engine_water().is_some())
+            .collect();
+        if with_engines.is_empty() {
+            return Ok(());
+        }
+        let building = SingleSelectMenu::new("Select a building:", with_engines).interact()?;
+        let Some(season) = building.engine_water() else {
+            return Ok(());
+        };
+        let title = format!(
+            "What level are the {} engines in your {building}? (0-{MAX_ENGINE_LEVEL})",
+            season.water()
+        );
+        let level = prompt_number(&title, self.engines.level(building))?;
+        self.engines.set_level(building, level);
+        Ok(())
+    }
+
+    fn view_rain_engines(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        println!("-----------------------");
+        println!("Rain engines");
+        println!("-----------------------");
+        for (building, level) in &self.engines.levels {
+            let Some(season) = building.engine_water() else {
+                continue;
+            };
+            let count = self.build_queue.count(*building);
+            if count == 0 {
+                println!(" > {building} level {level}: not in the build queue, burns nothing");
+                continue;
+            }
+            println!(
+                " > {building} x{count} level {level}: {:.1} {}/min, +{:.0}% production",
+                self.engines.consumption(*building) * count as f32,
+                season.water(),
+                self.engines.boost(*building) * 100.0
             );
-            need_counter.insert(
-                Need::BuildingMaterial(BuildingMaterial::Bricks),
-                num_species,
+        }
+        println!("-----------------------");
+        let year: u32 = all_seasons()
+            .into_iter()
+            .map(|season| self.seasons.length(season))
+            .sum();
+        for (season, demand) in self.engines.water_demand(&self.build_queue) {
+            if demand <= 0.0 {
+                continue;
+            }
+            let water = season.water();
+            let stock = self.inventory.stock_of(water);
+            let status = if stock as f32 >= demand * year as f32 {
+                Status::Available
+            } else {
+                Status::Missing
+            };
+            println!(
+                " > {}: {demand:.1}/min, {:.0} per year, {stock} in stock",
+                self.theme.paint(water, status),
+                demand * year as f32
             );
         }
+        println!("-----------------------");
+        println!(
+            "Blightrot risk: {}, about {:.2} cysts per minute",
+            self.engines.blightrot_risk(&self.build_queue),
+            self.engines.cysts_per_minute(&self.build_queue)
+        );
+        pause()
+    }
+
+    fn edit_blight(&mut self) -> Result<(), InquireError> {
+        self.blight.cysts =
+            prompt_number("How many Blightrot cysts are there now?", self.blight.cysts)?;
+        Ok(())
+    }
+
+    fn view_blight(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        let modifiers = self.modifiers();
+        let year: u32 = all_seasons()
+            .into_iter()
+            .map(|season| self.seasons.length(season))
+            .sum();
+        let fire = self
+            .blight
+            .fire_needed(&self.engines, &self.build_queue, year);
+        let stock = self.inventory.stock_of(purging_fire());
+        let status = if stock as f32 >= fire {
+            Status::Available
+        } else {
+            Status::Missing
+        };
+        println!("-----------------------");
+        println!("Blight");
+        println!("-----------------------");
+        println!(
+            " > {} cysts now, {:.2} more per minute from the rain engines",
+            self.blight.cysts,
+            self.engines.cysts_per_minute(&self.build_queue)
+        );
+        println!(
+            " > {:.0} cysts to burn within a year",
+            self.blight
+                .cysts_within(&self.engines, &self.build_queue, year)
+        );
+        println!(
+            " > {}: {fire:.0} needed, {stock} in stock",
+            self.theme.paint(purging_fire(), status)
+        );
+        println!(
+            " > Fuel: {:.0} to make it, {:.1} per minute for new cysts",
+            self.blight
+                .fuel_needed(&self.engines, &self.build_queue, year, &modifiers),
+            self.blight
+                .fuel_per_minute(&self.engines, &self.build_queue, &modifiers)
+        );
+        println!("-----------------------");
+        pause()
+    }
+
+    fn edit_fuel_budget(&mut self) -> Result<(), InquireError> {
+        self.fuel_budget.hearths =
+            prompt_number("How many hearths are burning?", self.fuel_budget.hearths)?;
+        self.fuel_budget.hearth_burn = prompt_number(
+            "How much fuel does each hearth burn per minute?",
+            self.fuel_budget.hearth_burn,
+        )?;
+        self.fuel_budget.crafts_per_minute = prompt_number(
+            "How many times per minute is each recipe crafted?",
+            self.fuel_budget.crafts_per_minute,
+        )?;
+        for fuel in all_fuel() {
+            let title = format!("How much {fuel} do you produce per minute?");
+            let production = prompt_number(&title, self.fuel_budget.production_of(fuel))?;
+            self.fuel_budget.production.insert(fuel, production);
+        }
+        Ok(())
+    }
+
+    fn view_fuel_budget(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        let modifiers = self.modifiers();
+        let active_recipes: Vec<Resource> = self
+            .goods_with(Availability::Produced)
+            .into_iter()
+            .filter(|good| self.fuel_budget.recipe_demand(good, &modifiers) > 0.0)
+            .collect();
+
+        let mut sources: Vec<(String, f32)> = vec![(
+            format!("Hearth x{}", self.fuel_budget.hearths),
+            self.fuel_budget.hearth_demand(&modifiers),
+        )];
+        for good in &active_recipes {
+            sources.push((
+                good.to_string(),
+                self.fuel_budget.recipe_demand(good, &modifiers),
+            ));
+        }
+        sources.push((
+            format!("{} for rain engine cysts", purging_fire()),
+            self.blight
+                .fuel_per_minute(&self.engines, &self.build_queue, &modifiers),
+        ));
+        sources.push((
+            format!("{} for current cysts this season", purging_fire()),
+            self.blight
+                .existing_fuel_per_minute(self.seasons.minutes_left(), &modifiers),
+        ));
+        sources.retain(|(_, demand)| *demand > 0.0);
+
+        println!("-----------------------");
+        println!("Fuel demand per minute");
+        println!("-----------------------");
+        let mut per_fuel: BTreeMap<Resource, f32> = BTreeMap::new();
+        for (source, demand) in &sources {
+            let split = self.fuel_budget.split(*demand);
+            let shares: Vec<String> = split
+                .iter()
+                .filter(|(_, share)| *share > 0.0)
+                .map(|(fuel, share)| format!("{share:.1} {fuel}"))
+                .collect();
+            println!(" > {source}: {demand:.1} ({})", pluralize(&shares, "and"));
+            for (fuel, share) in split {
+                *per_fuel.entry(fuel).or_default() += share;
+            }
+        }
+        println!("-----------------------");
+        println!("Per fuel, burned against produced per minute");
+        println!("-----------------------");
+        for fuel in all_fuel() {
+            let burned = per_fuel.get(&fuel).copied().unwrap_or_default();
+            let produced = self.fuel_budget.production_of(fuel);
+            let status = if produced >= burned {
+                Status::Available
+            } else {
+                Status::Missing
+            };
+            println!(
+                " > {}: {burned:.1} of {produced:.1}",
+                self.theme.paint(fuel, status)
+            );
+        }
+        println!("-----------------------");
+        let demand: f32 = sources.iter().map(|(_, demand)| demand).sum();
+        let production = self.fuel_budget.total_production();
+        let (status, verdict) = if production >= demand {
+            (Status::Available, "covered")
+        } else {
+            (Status::Missing, "short")
+        };
+        println!(
+            "{}: producing {production:.1} of {demand:.1} per minute",
+            self.theme.paint(verdict, status)
+        );
+        pause()
+    }
+
+    fn edit_population(&mut self) -> Result<(), InquireError> {
+        let species =
+            SingleSelectMenu::new("Select a species:", self.species.get_selections()).interact()?;
+        let title = format!("How many {species} live in your settlement?");
+        let population = prompt_number(&title, self.workforce.population_of(species))?;
+        self.workforce.population.insert(species, population);
+        Ok(())
+    }
+
+    fn view_workers(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        let assignments = self.workforce.allocate(&self.build_queue.buildings);
+        println!("-----------------------");
+        println!("Workers");
+        println!("-----------------------");
+        for (building, count) in &self.build_queue.buildings {
+            let staff: Vec<String> = assignments
+                .iter()
+                .filter(|assignment| assignment.building == *building)
+                .map(|assignment| {
+                    let status = if assignment.specialized {
+                        Status::Available
+                    } else {
+                        Status::Planned
+                    };
+                    format!(
+                        "{} {}",
+                        assignment.workers,
+                        self.theme.paint(assignment.species, status)
+                    )
+                })
+                .collect();
+            let staffed: u32 = assignments
+                .iter()
+                .filter(|assignment| assignment.building == *building)
+                .map(|assignment| assignment.workers)
+                .sum();
+            let open = count * WORKPLACES_PER_BUILDING - staffed;
+            println!(
+                " > {building} x{count} ({}): {}{}",
+                building.specialization(),
+                pluralize(&staff, "and"),
+                if open > 0 {
+                    format!(", {} open", self.theme.paint(open, Status::Missing))
+                } else {
+                    String::new()
+                }
+            );
+        }
+        println!("-----------------------");
+        for species in self.species.get_selections() {
+            let specialists: u32 = assignments
+                .iter()
+                .filter(|assignment| assignment.species == species && assignment.specialized)
+                .map(|assignment| assignment.workers)
+                .sum();
+            println!(
+                " > {species} ({}): {specialists} in specialised work{}",
+                pluralize(&species.specializations(), "and"),
+                if specialists > 0 {
+                    ", resolve bonus"
+                } else {
+                    ""
+                }
+            );
+        }
+        let idle: Vec<String> = self
+            .workforce
+            .idle(&assignments)
+            .iter()
+            .map(|(species, idle)| format!("{idle} {species}"))
+            .collect();
+        if !idle.is_empty() {
+            println!("-----------------------");
+            println!("Idle: {}", pluralize(&idle, "and"));
+        }
+        pause()
+    }
+
+    fn edit_build_queue(&mut self) -> Result<(), InquireError> {
+        if self.profile.buildings.is_empty() {
+            return Ok(());
+        }
+        let building = SingleSelectMenu::new("Select a building:", self.profile.buildings.clone())
+            .interact()?;
+        let costs: Vec<String> = building
+            .construction_cost()
+            .iter()
+            .map(|(material, amount)| format!("{amount} {material}"))
+            .collect();
+        let title = format!(
+            "How many {building} buildings do you plan to build? ({} each)",
+            pluralize(&costs, "and")
+        );
+        let count = prompt_number(&title, self.build_queue.count(building))?;
+        self.build_queue.set_count(building, count);
+        Ok(())
+    }
+
+    fn edit_housing(&mut self) -> Result<(), InquireError> {
+        let housing = SingleSelectMenu::new("Select a house:", all_housing()).interact()?;
+        let title = format!("How many {housing}s have you built or queued?");
+        let count = prompt_number(&title, self.build_queue.house_count(housing))?;
+        self.build_queue.set_house_count(housing, count);
+        Ok(())
+    }
+
+    fn plan_embarkation(&mut self) -> Result<(), InquireError> {
+        let points = prompt_number(
+            "How many embark points can you spend?",
+            self.profile.embark_points(),
+        )?;
+        let modifiers = self.modifiers();
+        let needs: Vec<Need> = self
+            .species
+            .get_selections()
+            .iter()
+            .flat_map(|species| modifiers.needs(*species))
+            .collect();
+        let options = all_embark_options()
+            .into_iter()
+            .filter(|option| self.profile.allows(option))
+            .collect();
+        let loadout = plan_embarkation(
+            &needs,
+            &self.available_goods(),
+            options,
+            points,
+            self.biome,
+            &modifiers,
+        );
+
+        clear_screen();
+        println!("-----------------------");
+        println!(
+            "Embarking with {} for {points} pts",
+            pluralize(&self.species.get_selection_strings(), "and")
+        );
+        println!("-----------------------");
+        for option in &loadout {
+            println!(" > {option}");
+        }
+        println!("-----------------------");
+        let spent: u32 = loadout.iter().map(|option| option.cost).sum();
+        println!("{spent}/{points} pts spent");
+        pause()
+    }
+
+    fn recommend_species(&mut self) -> Result<(), InquireError> {
+        let mut candidates = MultiSelectMenu::new(
+            "Select the species you could settle with:",
+            self.profile.species.clone(),
+        );
+        candidates.select(self.species.get_selections());
+        candidates.interact()?;
+        let modifiers = self.modifiers();
+
+        clear_screen();
+        println!("-----------------------");
+        println!("Your lineup");
+        println!("-----------------------");
+        print_compatibility(&Compatibility::of(&candidates.get_selections(), &modifiers));
+        println!("-----------------------");
+        println!("Best lineups of three");
+        println!("-----------------------");
+        let lineups = best_combinations(&candidates.get_selections(), 3, &modifiers);
+        if lineups.is_empty() {
+            println!("Select at least three candidates to compare lineups");
+        }
+        for compatibility in lineups.iter().take(5) {
+            print_compatibility(compatibility);
+        }
+        println!("-----------------------");
+        pause()
+    }
+
+    fn recommend_service_building(&mut self) -> Result<(), InquireError> {
+        let modifiers = self.modifiers();
+        let needs: Vec<Need> = self
+            .species
+            .get_selections()
+            .iter()
+            .flat_map(|species| modifiers.needs(*species))
+            .collect();
+        let available = self.available_goods();
+
+        clear_screen();
+        println!("-----------------------");
+        println!("Service buildings");
+        println!("-----------------------");
+        for (building, coverage) in best_service_buildings(
+            &self.profile.service_buildings,
+            &needs,
+            &available,
+            &modifiers,
+        ) {
+            let services: Vec<String> = building
+                .services(&modifiers)
+                .iter()
+                .map(|(service, goods)| {
+                    let goods: Vec<String> = goods
+                        .iter()
+                        .map(|good| {
+                            let status = if available.contains(good) {
+                                Status::Available
+                            } else {
+                                Status::Missing
+                            };
+                            self.theme.paint(good, status)
+                        })
+                        .collect();
+                    format!("{service} with {}", pluralize(&goods, "or"))
+                })
+                .collect();
+            println!(
+                " > {building}: covers {coverage} needs ({})",
+                services.join(", ")
+            );
+        }
+        println!("-----------------------");
+        pause()
+    }
+
+    fn add_order(&mut self) -> Result<(), InquireError> {
+        let good = SingleSelectMenu::new("What does the order ask for?", all_goods()).interact()?;
+        let title = format!("How many {good} does the order need?");
+        let quantity = prompt_number(&title, 1)?;
+        let goal =
+            SingleSelectMenu::new("Does it want them delivered or in production?", all_goals())
+                .interact()?;
+        self.orders.add(Order {
+            good,
+            quantity,
+            goal,
+        });
+        Ok(())
+    }
+
+    fn complete_order(&mut self) -> Result<(), InquireError> {
+        if self.orders.active.is_empty() {
+            return Ok(());
+        }
+        let order =
+            SingleSelectMenu::new("Which order is done?", self.orders.active.clone()).interact()?;
+        self.orders.complete(order);
+        Ok(())
+    }
+
+    fn add_glade_event(&mut self) -> Result<(), InquireError> {
+        let name = prompt_text("What is the glade event called?")?;
+        let dangerous = confirm("Is it in a dangerous glade?", false)?;
+        let mut requirements = Vec::new();
+        loop {
+            let good =
+                SingleSelectMenu::new("What does solving it take?", all_goods()).interact()?;
+            let title = format!("How many {good}?");
+            requirements.push((good, prompt_number(&title, 1)?));
+            if !confirm("Does it need another good?", false)? {
+                break;
+            }
+        }
+        let minutes_left = prompt_number("How many minutes are left to solve it?", 10)?;
+        self.glade_events.add(GladeEvent {
+            name,
+            requirements,
+            deadline: self.seasons.minute() + minutes_left,
+            dangerous,
+        });
+        Ok(())
+    }
+
+    fn resolve_glade_event(&mut self) -> Result<(), InquireError> {
+        if self.glade_events.active.is_empty() {
+            return Ok(());
+        }
+        let event = SingleSelectMenu::new(
+            "Which glade event is solved or abandoned?",
+            self.glade_events.active.iter(),
+        )
+        .interact()?
+        .clone();
+        self.glade_events.resolve(&event);
+        Ok(())
+    }
+
+    fn visit_trader(&mut self) -> Result<(), InquireError> {
+        let mut trader_stock = MultiSelectMenu::new("What is the trader selling?", all_goods());
+        trader_stock.interact()?;
+        let mut surplus = MultiSelectMenu::new("What could you spare?", all_goods());
+        surplus.interact()?;
+
+        let modifiers = self.modifiers();
+        // whatever the plan wants, so nothing it still needs is sold off
+        let mut needs: Vec<Need> = self
+            .species
+            .get_selections()
+            .iter()
+            .flat_map(|species| modifiers.needs(*species))
+            .chain(self.demand().into_keys())
+            .collect();
+        needs.sort();
+        needs.dedup();
+        let advice = advise_trade(
+            &needs,
+            &self.available_goods(),
+            &trader_stock.get_selections(),
+            &surplus.get_selections(),
+            &modifiers,
+        );
+
+        clear_screen();
+        println!("-----------------------");
+        println!("Buy");
+        println!("-----------------------");
+        for (good, unlocked) in &advice.buys {
+            println!(
+                " > {good} ({:.2} amber): unlocks {unlocked} needs or ingredient slots",
+                good.trade_value()
+            );
+        }
+        println!("-----------------------");
+        println!("Sell");
+        println!("-----------------------");
+        for good in &advice.sells {
+            println!(" > {good} ({:.2} amber)", good.trade_value());
+        }
+        println!("-----------------------");
+        pause()
+    }
+
+    fn available_goods(&self) -> Vec<Resource> {
+        all_goods()
+            .into_iter()
+            .filter(|good| {
+                self.goods_menu(*good)
+                    .get_availability(*good)
+                    .is_some_and(|availability| availability.is_available())
+            })
+            .collect()
+    }
+
+    fn goods_with(&self, availability: Availability) -> Vec<Resource> {
+        all_goods()
+            .into_iter()
+            .filter(|good| self.goods_menu(*good).get_availability(*good) == Some(availability))
+            .collect()
+    }
+
+    fn availability_of(&self, good: Resource) -> Option<Availability> {
+        self.goods_menu(good).get_availability(good)
+    }
+
+    fn goods_menu(&self, good: Resource) -> &MultiSelectMenu<Resource> {
+        match good {
+            Resource::Fuel(_) => &self.fuel,
+            Resource::CraftingResource(_) => &self.crafting_resources,
+            Resource::BuildingMaterial(_) => &self.building_materials,
+            Resource::ConsumableItem(_) => &self.consumable_items,
+            Resource::SimpleFood(_) => &self.simple_food,
+            Resource::ComplexFood(_) => &self.complex_food,
+            Resource::Clothing(_) => &self.clothing,
+        }
+    }
+
+    fn goods_menu_mut(&mut self, good: Resource) -> &mut MultiSelectMenu<Resource> {
+        match good {
+            Resource::Fuel(_) => &mut self.fuel,
+            Resource::CraftingResource(_) => &mut self.crafting_resources,
+            Resource::BuildingMaterial(_) => &mut self.building_materials,
+            Resource::ConsumableItem(_) => &mut self.consumable_items,
+            Resource::SimpleFood(_) => &mut self.simple_food,
+            Resource::ComplexFood(_) => &mut self.complex_food,
+            Resource::Clothing(_) => &mut self.clothing,
+        }
+    }
+
+    fn edit_biome(&mut self) -> Result<(), InquireError> {
+        self.biome = Some(self.biomes.interact()?);
+        Ok(())
+    }
+
+    fn edit_difficulty(&mut self) -> Result<(), InquireError> {
+        self.difficulty = Some(self.difficulties.interact()?);
+        Ok(())
+    }
+
+    fn edit_perks(&mut self) -> Result<(), InquireError> {
+        self.perks.interact()
+    }
+
+    pub fn plan_code(&self) -> PlanCode {
+        PlanCode {
+            species: self.species.get_selections(),
+            services: self.services.get_selections(),
+            goods: all_goods()
+                .into_iter()
+                .filter_map(|good| {
+                    self.goods_menu(good)
+                        .get_availability(good)
+                        .map(|availability| (good, availability))
+                })
+                .filter(|(_, availability)| *availability != Availability::Unavailable)
+                .collect(),
+        }
+    }
+
+    pub fn apply_plan_code(&mut self, plan: &PlanCode) {
+        self.species.select(plan.species.clone());
+        self.services.select(plan.services.clone());
+        for good in all_goods() {
+            let availability = plan
+                .goods
+                .iter()
+                .find(|(selected, _)| *selected == good)
+                .map(|(_, availability)| *availability)
+                .unwrap_or(Availability::Unavailable);
+            self.goods_menu_mut(good)
+                .set_availability(good, availability);
+        }
+    }
+
+    fn share_plan(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        println!("-----------------------");
+        println!("Plan code");
+        println!("-----------------------");
+        println!("{}", self.plan_code().encode());
+        println!("-----------------------");
+        pause()
+    }
+
+    fn import_plan(&mut self) -> Result<(), InquireError> {
+        let code = prompt_text("Paste a plan code:")?;
+        match PlanCode::decode(&code) {
+            Ok(plan) => {
+                self.apply_plan_code(&plan);
+                Ok(())
+            }
+            Err(e) => {
+                println!("{e}");
+                pause()
+            }
+        }
+    }
+
+    fn view_timeline(&mut self) -> Result<(), InquireError> {
+        clear_screen();
+        let mut last_moment = None;
+        for (moment, milestone) in &self.timeline.entries {
+            if last_moment != Some(moment) {
+                println!("-----------------------");
+                println!("{moment}");
+                println!("-----------------------");
+                last_moment = Some(moment);
+            }
+            match milestone {
+                Milestone::Selected(facet, availability) => {
+                    println!(" > {facet}: {availability}")
+                }
+                Milestone::Removed(facet) => println!(" > {facet}: Unavailable"),
+                Milestone::Joined(species) => println!(" > {species} joined"),
+                Milestone::Left(species) => println!(" > {species} left"),
+                Milestone::Blueprint(building) => println!(" > {building} planned"),
+                Milestone::Dropped(building) => println!(" > {building} dropped"),
+                Milestone::Satisfied(need) => {
+                    println!(" > {} satisfied", self.theme.paint(need, Status::Available))
+                }
+            }
+        }
+        println!("-----------------------");
+        println!("Needs satisfied");
+        println!("-----------------------");
+        for (moment, need) in self.timeline.satisfied() {
+            println!(" > {need}: {moment}");
+        }
+        println!("-----------------------");
+        pause()
+    }
+
+    fn end_run(&mut self) -> Result<(), InquireError> {
+        let outcome = SingleSelectMenu::new("How did the run end?", all_outcomes()).interact()?;
+        let run = Run {
+            species: self.species.get_selections(),
+            biome: self.biome,
+            blueprints: self.build_queue.buildings.keys().copied().collect(),
+            satisfied: self
+                .timeline
+                .satisfied()
+                .into_iter()
+                .map(|(moment, need)| (need, moment))
+                .collect(),
+            unmet: self.unmet_needs(),
+            outcome,
+        };
+        if let Err(e) = RunHistory::append(&run) {
+            println!("Couldn't save the run: {e}");
+            return pause();
+        }
+        // the next run starts its clock over, season lengths are settings and carry on
+        self.timeline = Timeline::default();
+        self.seasons = SeasonCycle {
+            lengths: std::mem::take(&mut self.seasons.lengths),
+            ..SeasonCycle::default()
+        };
+        Ok(())
+    }
+
+    fn view_run_history(&mut self) -> Result<(), InquireError> {
+        let history = match RunHistory::load() {
+            Ok(history) => history,
+            Err(e) => {
+                println!("Couldn't read the run history: {e}");
+                return pause();
+            }
+        };
+        clear_screen();
+        println!("-----------------------");
+        println!("Win rate by lineup ({} runs)", history.runs.len());
+        println!("-----------------------");
+        if history.skipped > 0 {
+            println!("Skipped {} unreadable runs", history.skipped);
+        }
+        for (species, wins, played) in history.win_rates() {
+            println!(
+                " > {}: {wins}/{played} won ({:.0}%)",
+                pluralize(&species, "and"),
+                wins as f32 / played as f32 * 100.0
+            );
+        }
+        println!("-----------------------");
+        println!("Most often missing");
+        println!("-----------------------");
+        for (need, count) in history.missing_needs().iter().take(5) {
+            println!(" > {need}: unmet in {count} runs");
+        }
+        println!("-----------------------");
+        println!("Average time to satisfy");
+        println!("-----------------------");
+        for (need, seasons) in history.average_time_to_satisfy() {
+            println!(" > {need}: {seasons:.1} seasons in");
+        }
+        println!("-----------------------");
+        pause()
+    }
+
+    fn chain_rarity(&self, good: &(impl Recipe + Display), modifiers: &Modifiers) -> Rarity {
+        self.biome
+            .map_or(Rarity::Common, |biome| biome.chain_rarity(good, modifiers))
+    }
+
+    fn rarity_note(&self, good: Resource) -> String {
+        match self.biome.and_then(|biome| biome.rarity(good)) {
+            Some(Rarity::Rare) => " (rare here)".to_string(),
+            Some(Rarity::Impossible) => " (not found here)".to_string(),
+            Some(Rarity::Common) | None => String::new(),
+        }
+    }
+
+    /// Every need of the selected species, once.
+    pub fn needs(&self) -> Vec<Need> {
+        let modifiers = self.modifiers();
+        let mut needs: Vec<Need> = self
+            .species
+            .get_selections()
+            .iter()
+            .flat_map(|species| modifiers.needs(*species))
+            .collect();
+        needs.sort();
+        needs.dedup();
+        needs
+    }
+
+    pub fn met_needs(&self) -> Vec<Need> {
+        let modifiers = self.modifiers();
+        let selected_facets = self.selected_facets();
+        self.needs()
+            .into_iter()
+            .filter(|need| is_met(need, &selected_facets, &self.build_queue, &modifiers))
+            .collect()
+    }
+
+    fn unmet_needs(&self) -> Vec<Need> {
+        let met = self.met_needs();
+        self.needs()
+            .into_iter()
+            .filter(|need| !met.contains(need))
+            .collect()
+    }
+
+    fn selected_facets(&self) -> BTreeMap<String, Availability> {
+        [
+            self.services.get_availability_strings(),
+            self.fuel.get_availability_strings(),
+            self.crafting_resources.get_availability_strings(),
+            self.building_materials.get_availability_strings(),
+            self.consumable_items.get_availability_strings(),
+            self.simple_food.get_availability_strings(),
+            self.complex_food.get_availability_strings(),
+            self.clothing.get_availability_strings(),
+        ]
+        .concat()
+        .into_iter()
+        .collect()
+    }
+
+    /// Orders already met, then the ones still open.
+    fn partition_orders(&self) -> (Vec<&Order>, Vec<&Order>) {
+        self.orders.active.iter().partition(|order| {
+            order.is_fulfilled(&self.inventory, self.availability_of(order.good))
+        })
+    }
+
+    /// Glade events still to be worked towards, then the ones ready or past their deadline.
+    fn partition_glade_events(&self) -> (Vec<&GladeEvent>, Vec<&GladeEvent>) {
+        let now = self.seasons.minute();
+        self.glade_events.active.iter().partition(|event| {
+            event.minutes_left(now).is_some() && !event.is_ready(&self.inventory)
+        })
+    }
+
+    /// Goods wanted for something other than a species need, with how many are still wanted.
+    fn demand(&self) -> BTreeMap<Need, u32> {
+        let mut demand: BTreeMap<Need, u32> = BTreeMap::new();
+        self.build_queue
+            .materials()
+            .iter()
+            .for_each(|(material, amount)| {
+                *demand.entry(Need::from(*material)).or_default() += amount;
+            });
+        self.partition_orders().1.iter().for_each(|order| {
+            *demand.entry(Need::from(order.good)).or_default() +=
+                order.remaining(&self.inventory, self.availability_of(order.good));
+        });
+        self.partition_glade_events().0.iter().for_each(|event| {
+            event.requirements.iter().for_each(|(good, quantity)| {
+                *demand.entry(Need::from(*good)).or_default() +=
+                    quantity.saturating_sub(self.inventory.stock_of(*good));
+            })
+        });
+        // cysts left to grow spread blightrot to everyone
+        let fire = self.blight.fire_needed(
+            &self.engines,
+            &self.build_queue,
+            self.seasons.minutes_left(),
+        );
+        if fire > 0.0 {
+            *demand.entry(Need::from(purging_fire())).or_default() += fire.ceil() as u32;
+        }
+        demand
+    }
+
+    fn print_needs(&self) {
+        let modifiers = self.modifiers();
+        let mut need_counter: BTreeMap<Need, usize> = BTreeMap::new();
+        let demand = self.demand();
+        let selected_species = self.species.get_selections();
+        let num_species = selected_species.len();
+        let construction = self.build_queue.materials();
+        let (ready_orders, open_orders) = self.partition_orders();
+        let now = self.seasons.minute();
+        let (open_events, closed_events) = self.partition_glade_events();
+        let cysts = self.blight.cysts_within(
+            &self.engines,
+            &self.build_queue,
+            self.seasons.minutes_left(),
+        );
         selected_species.iter().for_each(|species| {
-            species
-                .needs()
+            modifiers
+                .needs(*species)
                 .iter()
                 .for_each(|need| match need_counter.get(need) {
                     Some(need_count) => {
@@ -159,45 +1257,132 @@ impl Economy {
                 })
         });
 
+        demand.keys().for_each(|need| {
+            need_counter.entry(*need).or_default();
+        });
+        let demand_of = |need: &Need| demand.get(need).copied().unwrap_or_default();
+        // a resolve penalty has to be made up, so needs that give resolve go first
+        let resolve_of = |need: &Need| {
+            if modifiers.resolve_penalty() > 0 {
+                need.resolve()
+            } else {
+                0
+            }
+        };
+        // demands outside the species come first, then the needs most species share. under a
+        // resolve penalty the needs worth the most resolve go before anything else.
         let mut need_count: Vec<(&Need, &usize)> = need_counter.iter().collect();
-        need_count.sort_by(|a, b| b.1.cmp(a.1));
-
-        let selected_facets = [
-            self.services.get_selection_strings(),
-            self.fuel.get_selection_strings(),
-            self.crafting_resources.get_selection_strings(),
-            self.building_materials.get_selection_strings(),
-            self.consumable_items.get_selection_strings(),
-            self.simple_food.get_selection_strings(),
-            self.complex_food.get_selection_strings(),
-            self.clothing.get_selection_strings(),
-        ]
-        .concat();
+        need_count.sort_by(|a, b| {
+            resolve_of(b.0)
+                .cmp(&resolve_of(a.0))
+                .then_with(|| (*a.1 != 0).cmp(&(*b.1 != 0)))
+                .then_with(|| b.1.cmp(a.1))
+                .then_with(|| demand_of(b.0).cmp(&demand_of(a.0)))
+                .then_with(|| {
+                    self.chain_rarity(a.0, &modifiers)
+                        .cmp(&self.chain_rarity(b.0, &modifiers))
+                })
+        });
 
-        let mut last_count = None;
+        let selected_facets = self.selected_facets();
+
+        let mut season_locked: BTreeMap<String, Season> = BTreeMap::new();
+        let mut last_heading = None;
         for (need, count) in need_count {
-            if *count >= num_species / 2 {
-                if last_count != Some(count) {
+            // every species lives somewhere, so houses are listed however few want them
+            if *count == 0 || *count >= num_species / 2 || matches!(need, Need::Housing(_)) {
+                note_season_lock(*need, &mut season_locked, &modifiers);
+                let resolve = resolve_of(need);
+                if last_heading != Some((resolve, count)) {
                     println!("-----------------------");
-                    println!("Needed by {count}/{} species", num_species);
+                    if *count == 0 {
+                        println!("Construction, orders and events");
+                    } else if resolve > 0 {
+                        println!(
+                            "Worth {resolve} resolve against the penalty, needed by {count}/{num_species} species"
+                        );
+                    } else {
+                        println!("Needed by {count}/{} species", num_species);
+                    }
                     println!("-----------------------");
-                    last_count = Some(count);
+                    last_heading = Some((resolve, count));
                 }
 
-                println!(" > {}", colorize(need, &selected_facets));
+                let mut notes: Vec<String> = construction
+                    .iter()
+                    .filter(|(material, _)| Need::from(*material) == *need)
+                    .map(|(_, amount)| format!("{amount} to build"))
+                    .collect();
+                if *need == Need::from(purging_fire()) {
+                    notes.push(format!("{cysts:.0} cysts to burn"));
+                }
+                modifiers.goods(need).iter().for_each(|good| {
+                    open_orders
+                        .iter()
+                        .filter(|order| order.good == *good)
+                        .for_each(|order| {
+                            notes.push(format!(
+                                "order {}/{}{}",
+                                order.progress(&self.inventory),
+                                order.quantity,
+                                match order.goal {
+                                    Goal::Deliver => "",
+                                    Goal::Produce => " and producing",
+                                }
+                            ))
+                        });
+                    open_events
+                        .iter()
+                        .filter(|event| event.requirement_for(*good).is_some())
+                        .for_each(|event| {
+                            notes.push(format!(
+                                "{} {}/{} within {} min",
+                                event.name,
+                                self.inventory.stock_of(*good),
+                                event.requirement_for(*good).unwrap_or_default(),
+                                event.minutes_left(now).unwrap_or_default()
+                            ))
+                        });
+                });
+                let mut notes = if notes.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", notes.join(", "))
+                };
+                let painted = match need {
+                    Need::Housing(housing) if self.build_queue.house_count(*housing) > 0 => {
+                        notes = format!(" ({} queued)", self.build_queue.house_count(*housing));
+                        self.theme.paint(need, Status::Available)
+                    }
+                    _ => colorize(*need, &selected_facets, self.theme, &modifiers),
+                };
+                println!(" > {painted}{notes}");
 
-                for ingredient_slot in need.recipe() {
+                for mut ingredient_slot in modifiers.recipe(need) {
+                    ingredient_slot
+                        .sort_by_key(|ingredient| self.chain_rarity(ingredient, &modifiers));
                     let technicolor_ingredient_slot: Vec<String> = ingredient_slot
                         .iter()
-                        .map(|ingredient| colorize(ingredient, &selected_facets))
+                        .map(|ingredient| {
+                            note_season_lock(*ingredient, &mut season_locked, &modifiers);
+                            colorize(*ingredient, &selected_facets, self.theme, &modifiers)
+                                + &self.rarity_note(*ingredient)
+                        })
                         .collect();
                     println!("  > {}", pluralize(&technicolor_ingredient_slot, "or"));
                     if ingredient_slot.len() == 1 {
                         // this is mostly for flour.
-                        for nested_slot in ingredient_slot[0].recipe() {
+                        for mut nested_slot in modifiers.recipe(&ingredient_slot[0]) {
+                            nested_slot.sort_by_key(|ingredient| {
+                                self.chain_rarity(ingredient, &modifiers)
+                            });
                             let technicolor_nested_slot: Vec<String> = nested_slot
                                 .iter()
-                                .map(|ingredient| colorize(ingredient, &selected_facets))
+                                .map(|ingredient| {
+                                    note_season_lock(*ingredient, &mut season_locked, &modifiers);
+                                    colorize(*ingredient, &selected_facets, self.theme, &modifiers)
+                                        + &self.rarity_note(*ingredient)
+                                })
                                 .collect();
                             println!("    > {}", pluralize(&technicolor_nested_slot, "or"));
                         }
@@ -206,21 +1391,162 @@ impl Economy {
             }
         }
         println!("-----------------------");
+
+        if !ready_orders.is_empty() {
+            let ready: Vec<String> = ready_orders.iter().map(|order| order.to_string()).collect();
+            println!("Orders ready to complete: {}", pluralize(&ready, "and"));
+        }
+        for (ready, heading) in [
+            (true, "Glade events ready to solve"),
+            (false, "Glade events past their deadline"),
+        ] {
+            let events: Vec<String> = closed_events
+                .iter()
+                .filter(|event| event.is_ready(&self.inventory) == ready)
+                .map(|event| event.name.clone())
+                .collect();
+            if !events.is_empty() {
+                println!("{heading}: {}", pluralize(&events, "and"));
+            }
+        }
+
+        for species in &selected_species {
+            let resolve = Resolve::of(*species, &modifiers, |need| {
+                is_met(need, &selected_facets, &self.build_queue, &modifiers)
+            });
+            let unmet: Vec<String> = resolve
+                .unmet
+                .iter()
+                .map(|need| self.theme.paint(need, Status::Missing))
+                .collect();
+            println!(
+                "{species} resolve: {}/{}{}",
+                resolve.score(),
+                resolve.max(),
+                if unmet.is_empty() {
+                    String::new()
+                } else {
+                    format!(", missing {}", pluralize(&unmet, "and"))
+                }
+            );
+        }
+
+        for (availability, warning) in [
+            (Availability::Stockpiled, "Stockpiled, will run out"),
+            (Availability::Depleted, "Deposits depleted"),
+        ] {
+            let goods: Vec<String> = selected_facets
+                .iter()
+                .filter(|(_, facet_availability)| **facet_availability == availability)
+                .map(|(facet, _)| facet.to_string())
+                .collect();
+            if !goods.is_empty() {
+                println!("{warning}: {}", pluralize(&goods, "and"));
+            }
+        }
+
+        for (good, season) in season_locked {
+            if season == self.seasons.current {
+                println!(
+                    "{good} can only be made during {season}, stockpile it before {season} ends in {} min",
+                    self.seasons.minutes_left()
+                );
+            } else {
+                println!(
+                    "{good} can only be made during {season}, which starts in {} min",
+                    self.seasons.minutes_until(season)
+                );
+            }
+        }
     }
 }
 
-fn colorize<T: Display>(facet: T, selected_facets: &[String]) -> String {
-    let color = if selected_facets.contains(&facet.to_string()) {
-        Color::Green
-    } else {
-        Color::Red
+fn print_compatibility(compatibility: &Compatibility) {
+    let species: Vec<String> = compatibility
+        .species
+        .iter()
+        .map(|species| species.to_string())
+        .collect();
+    println!(
+        " > {}: score {}, {} shared needs, {} shared raw inputs, {} chains",
+        pluralize(&species, "and"),
+        compatibility.score(),
+        compatibility.shared_needs,
+        compatibility.overlapping_inputs,
+        compatibility.chains
+    );
+}
+
+fn note_season_lock<T: Display + Recipe>(
+    facet: T,
+    season_locked: &mut BTreeMap<String, Season>,
+    modifiers: &Modifiers,
+) {
+    if let Some(season) = Season::locking(&facet, modifiers) {
+        season_locked.insert(facet.to_string(), season);
+    }
+}
+
+fn colorize<T: Display + Recipe>(
+    facet: T,
+    selected_facets: &BTreeMap<String, Availability>,
+    theme: Theme,
+    modifiers: &Modifiers,
+) -> String {
+    let status = match selected_facets.get(&facet.to_string()) {
+        Some(Availability::Produced | Availability::Traded) => Status::Available,
+        Some(Availability::Stockpiled) => Status::Stockpiled,
+        Some(Availability::Planned) => Status::Planned,
+        Some(Availability::Depleted) => Status::Depleted,
+        _ if is_chain(&facet, selected_facets, modifiers) => Status::Chain,
+        _ => Status::Missing,
     };
-    style(facet).with(color).to_string()
+    theme.paint(facet, status)
+}
+
+// houses aren't consumed, they're met once one is in the build queue.
+fn is_met(
+    need: &Need,
+    selected_facets: &BTreeMap<String, Availability>,
+    build_queue: &BuildQueue,
+    modifiers: &Modifiers,
+) -> bool {
+    let goods = modifiers.goods(need);
+    if let Need::Housing(housing) = need {
+        build_queue.house_count(*housing) > 0
+    } else if goods.is_empty() {
+        is_chain(need, selected_facets, modifiers)
+    } else {
+        goods.iter().any(|good| {
+            selected_facets
+                .get(&good.to_string())
+                .is_some_and(|availability| availability.is_available())
+        })
+    }
+}
+
+// a chain is a good we don't make yet but could, because every ingredient slot is covered.
+fn is_chain(
+    facet: &(impl Recipe + Display),
+    selected_facets: &BTreeMap<String, Availability>,
+    modifiers: &Modifiers,
+) -> bool {
+    let recipe = modifiers.recipe(facet);
+    !recipe.is_empty()
+        && recipe.iter().all(|ingredient_slot| {
+            ingredient_slot.iter().any(|ingredient| {
+                selected_facets
+                    .get(&ingredient.to_string())
+                    .is_some_and(|availability| availability.is_available())
+            })
+        })
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum MenuKind {
     EditSpecies,
+    EditBiome,
+    EditDifficulty,
     EditServices,
     EditFuel,
     EditCraftingResources,
@@ -229,6 +1555,41 @@ pub enum MenuKind {
     EditSimpleFood,
     EditComplexFood,
     EditClothing,
+    EditAvailability,
+    EditStock,
+    EditConsumption,
+    ViewForecast,
+    EditSeason,
+    EditSeasonLengths,
+    EditRainEngines,
+    ViewRainEngines,
+    EditBlight,
+    ViewBlight,
+    EditFuelBudget,
+    ViewFuelBudget,
+    EditBuildQueue,
+    EditHousing,
+    EditPopulation,
+    ViewWorkers,
+    PlanEmbarkation,
+    RecommendSpecies,
+    RecommendServiceBuilding,
+    AddOrder,
+    CompleteOrder,
+    VisitTrader,
+    AddGladeEvent,
+    ResolveGladeEvent,
+    EditPerks,
+    EditProfile,
+    ViewTimeline,
+    SharePlan,
+    ImportPlan,
+    EndRun,
+    ViewRunHistory,
+    NewSettlement,
+    SwitchSettlement,
+    CompareSettlements,
+    EditTheme,
 }
 
 impl Display for MenuKind {
@@ -248,5 +1609,42 @@ fn all_menus() -> Vec<MenuKind> {
         MenuKind::EditConsumableItems,
         MenuKind::EditServices,
         MenuKind::EditSpecies,
+        MenuKind::EditBiome,
+        MenuKind::EditDifficulty,
+        MenuKind::EditAvailability,
+        MenuKind::EditStock,
+        MenuKind::EditConsumption,
+        MenuKind::ViewForecast,
+        MenuKind::EditSeason,
+        MenuKind::EditSeasonLengths,
+        MenuKind::EditRainEngines,
+        MenuKind::ViewRainEngines,
+        MenuKind::EditBlight,
+        MenuKind::ViewBlight,
+        MenuKind::EditFuelBudget,
+        MenuKind::ViewFuelBudget,
+        MenuKind::EditBuildQueue,
+        MenuKind::EditHousing,
+        MenuKind::EditPopulation,
+        MenuKind::ViewWorkers,
+        MenuKind::PlanEmbarkation,
+        MenuKind::RecommendSpecies,
+        MenuKind::RecommendServiceBuilding,
+        MenuKind::AddOrder,
+        MenuKind::CompleteOrder,
+        MenuKind::VisitTrader,
+        MenuKind::AddGladeEvent,
+        MenuKind::ResolveGladeEvent,
+        MenuKind::EditPerks,
+        MenuKind::EditProfile,
+        MenuKind::ViewTimeline,
+        MenuKind::SharePlan,
+        MenuKind::ImportPlan,
+        MenuKind::EndRun,
+        MenuKind::ViewRunHistory,
+        MenuKind::NewSettlement,
+        MenuKind::SwitchSettlement,
+        MenuKind::CompareSettlements,
+        MenuKind::EditTheme,
     ]
 }
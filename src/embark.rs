@@ -0,0 +1,227 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    all_perks, resource::*, Biome, Building, Effect, Modifiers, Need, Perk, Rarity, Resource,
+    BLUEPRINT_CHOICES,
+};
+
+pub const DEFAULT_EMBARK_POINTS: u32 = 10;
+
+pub fn all_embark_options() -> Vec<EmbarkOption> {
+    let goods = [
+        (grain(), 15, 1),
+        (meat(), 15, 1),
+        (vegetables(), 15, 1),
+        (berries(), 15, 1),
+        (insects(), 15, 1),
+        (mushrooms(), 15, 1),
+        (eggs(), 15, 1),
+        (herbs(), 10, 1),
+        (leather(), 10, 1),
+        (plant_fiber(), 10, 1),
+        (reed(), 10, 1),
+        (clay(), 10, 1),
+        (copper_ore(), 10, 1),
+        (planks(), 10, 1),
+        (fabric(), 8, 1),
+        (bricks(), 8, 1),
+        (parts(), 4, 2),
+        (flour(), 10, 2),
+        (pottery(), 8, 2),
+        (barrels(), 8, 2),
+        (jerky(), 8, 2),
+        (ale(), 8, 2),
+        (coats(), 6, 2),
+        (boots(), 6, 2),
+    ]
+    .into_iter()
+    .map(|(good, amount, cost)| EmbarkOption {
+        embark: Embark::Goods(good, amount),
+        cost,
+    });
+
+    let blueprints = [
+        Building::Bakery,
+        Building::Brewery,
+        Building::Cookhouse,
+        Building::Granary,
+        Building::Leatherworker,
+        Building::Press,
+        Building::Smokehouse,
+        Building::Tinctury,
+    ]
+    .into_iter()
+    .map(|building| EmbarkOption {
+        embark: Embark::Blueprint(building),
+        cost: 3,
+    });
+
+    // starting effects that widen recipes, e.g. porridge made with roots
+    let effects = all_perks()
+        .into_iter()
+        .filter(|perk| {
+            perk.effects()
+                .iter()
+                .all(|effect| matches!(effect, Effect::AddOption { .. }))
+        })
+        .map(|perk| EmbarkOption {
+            embark: Embark::Effect(perk),
+            cost: 2,
+        });
+
+    goods.chain(blueprints).chain(effects).collect()
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Embark {
+    Goods(Resource, u32),
+    Blueprint(Building),
+    Effect(Perk),
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct EmbarkOption {
+    pub embark: Embark,
+    pub cost: u32,
+}
+
+impl EmbarkOption {
+    /// The goods this option gives you, or lets you make from the start.
+    pub fn covers(&self) -> Vec<Resource> {
+        match self.embark {
+            Embark::Goods(good, _) => vec![good],
+            Embark::Blueprint(building) => building.produces(),
+            Embark::Effect(_) => vec![],
+        }
+    }
+}
+
+impl Display for EmbarkOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.embark {
+            Embark::Goods(good, amount) => write!(f, "{amount} {good} ({} pts)", self.cost),
+            Embark::Blueprint(building) => {
+                write!(f, "{building} blueprint ({} pts)", self.cost)
+            }
+            Embark::Effect(perk) => write!(f, "{perk} ({} pts)", self.cost),
+        }
+    }
+}
+
+/// Greedily spends `points` on whichever of `options` covers the most unmet needs and ingredient gaps per point.
+///
+/// `needs` may list a need once for every species that has it, so shared needs weigh more. Raw
+/// resources that are common in the `biome` count for less, you'll gather them anyway.
+pub fn plan_embarkation(
+    needs: &[Need],
+    available: &[Resource],
+    mut options: Vec<EmbarkOption>,
+    mut points: u32,
+    biome: Option<Biome>,
+    modifiers: &Modifiers,
+) -> Vec<EmbarkOption> {
+    let mut covered: Vec<Resource> = available.to_vec();
+    let mut modifiers = modifiers.clone();
+    let mut loadout = Vec::new();
+
+    loop {
+        let best = options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.cost <= points)
+            .map(|(i, option)| {
+                let gaps = match option.embark {
+                    Embark::Effect(perk) => effect_gaps(perk, needs, &covered, &modifiers),
+                    _ => gaps_filled(option, needs, &covered, &modifiers),
+                };
+                let common = biome.is_some_and(|biome| {
+                    option
+                        .covers()
+                        .iter()
+                        .all(|good| biome.rarity(*good) == Some(Rarity::Common))
+                });
+                let weight = match option.embark {
+                    // the fewer blueprints a draft offers, the more a sure one is worth
+                    Embark::Blueprint(_) => {
+                        BLUEPRINT_CHOICES as f32 / modifiers.blueprint_choices() as f32
+                    }
+                    _ if common && !option.covers().is_empty() => 0.5,
+                    _ => 1.0,
+                };
+                (i, gaps as f32 * weight / option.cost as f32)
+            })
+            .filter(|(_, score)| *score > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        let Some((i, _)) = best else {
+            return loadout;
+        };
+        let option = options.remove(i);
+        points -= option.cost;
+        covered.extend(option.covers());
+        if let Embark::Effect(perk) = option.embark {
+            modifiers.perks.push(perk);
+        }
+        loadout.push(option);
+    }
+}
+
+fn gaps_filled(
+    option: &EmbarkOption,
+    needs: &[Need],
+    covered: &[Resource],
+    modifiers: &Modifiers,
+) -> usize {
+    let new_goods: Vec<Resource> = option
+        .covers()
+        .into_iter()
+        .filter(|good| !covered.contains(good))
+        .collect();
+
+    needs
+        .iter()
+        .map(|need| {
+            let goods = modifiers.goods(need);
+            let satisfied = goods.iter().any(|good| covered.contains(good));
+            if satisfied {
+                return 0;
+            }
+            let direct = goods.iter().any(|good| new_goods.contains(good));
+            let slots = modifiers
+                .recipe(need)
+                .iter()
+                .filter(|ingredient_slot| {
+                    !ingredient_slot.iter().any(|good| covered.contains(good))
+                        && ingredient_slot.iter().any(|good| new_goods.contains(good))
+                })
+                .count();
+            // a need handed over outright beats one that still has to be crafted
+            if direct {
+                2 + slots
+            } else {
+                slots
+            }
+        })
+        .sum()
+}
+
+/// Needs that become makeable from what's covered once the effect changes their recipes.
+fn effect_gaps(perk: Perk, needs: &[Need], covered: &[Resource], modifiers: &Modifiers) -> usize {
+    let mut with_effect = modifiers.clone();
+    with_effect.perks.push(perk);
+    let makeable = |need: &Need, modifiers: &Modifiers| {
+        let recipe = modifiers.recipe(need);
+        modifiers
+            .goods(need)
+            .iter()
+            .any(|good| covered.contains(good))
+            || (!recipe.is_empty()
+                && recipe.iter().all(|ingredient_slot| {
+                    ingredient_slot.iter().any(|good| covered.contains(good))
+                }))
+    };
+    needs
+        .iter()
+        .filter(|need| !makeable(need, modifiers) && makeable(need, &with_effect))
+        .count()
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+
+use crate::{pluralize, Inventory, Resource};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GladeEvent {
+    pub name: String,
+    pub requirements: Vec<(Resource, u32)>,
+    /// The minute of the run by which the event has to be solved or abandoned, see
+    /// `SeasonCycle::minute`.
+    pub deadline: u32,
+    pub dangerous: bool,
+}
+
+impl GladeEvent {
+    pub fn requirement_for(&self, good: Resource) -> Option<u32> {
+        self.requirements
+            .iter()
+            .find(|(required, _)| *required == good)
+            .map(|(_, quantity)| *quantity)
+    }
+
+    /// Minutes left at minute `now` of the run, `None` once the deadline has passed.
+    pub fn minutes_left(&self, now: u32) -> Option<u32> {
+        self.deadline.checked_sub(now)
+    }
+
+    pub fn is_ready(&self, inventory: &Inventory) -> bool {
+        self.requirements
+            .iter()
+            .all(|(good, quantity)| inventory.stock_of(*good) >= *quantity)
+    }
+}
+
+impl Display for GladeEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let requirements: Vec<String> = self
+            .requirements
+            .iter()
+            .map(|(good, quantity)| format!("{quantity} {good}"))
+            .collect();
+        write!(
+            f,
+            "{}{}: {}",
+            self.name,
+            if self.dangerous { " (dangerous)" } else { "" },
+            pluralize(&requirements, "and")
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GladeEvents {
+    pub active: Vec<GladeEvent>,
+}
+
+impl GladeEvents {
+    /// Events are kept most urgent first.
+    pub fn add(&mut self, event: GladeEvent) {
+        let i = self
+            .active
+            .partition_point(|active| active.deadline <= event.deadline);
+        self.active.insert(i, event);
+    }
+
+    pub fn resolve(&mut self, event: &GladeEvent) {
+        if let Some(i) = self.active.iter().position(|active| active == event) {
+            self.active.remove(i);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{all_fuel, Modifiers, Recipe, Resource};
+
+#[derive(Debug)]
+pub struct FuelBudget {
+    pub hearths: u32,
+    /// Fuel burned per minute by each hearth.
+    pub hearth_burn: f32,
+    /// How often every recipe that takes fuel is crafted per minute.
+    pub crafts_per_minute: f32,
+    pub production: HashMap<Resource, f32>,
+}
+
+impl Default for FuelBudget {
+    fn default() -> Self {
+        Self {
+            hearths: 1,
+            hearth_burn: 1.0,
+            crafts_per_minute: 0.5,
+            production: HashMap::new(),
+        }
+    }
+}
+
+impl FuelBudget {
+    pub fn hearth_demand(&self, modifiers: &Modifiers) -> f32 {
+        self.hearths as f32 * self.hearth_burn * modifiers.fuel_burn()
+    }
+
+    /// Fuel burned per minute while crafting `good`, zero when it doesn't take any.
+    pub fn recipe_demand(&self, good: &(impl Recipe + Display), modifiers: &Modifiers) -> f32 {
+        modifiers
+            .recipe(good)
+            .iter()
+            .filter(|ingredient_slot| is_fuel_slot(ingredient_slot))
+            .count() as f32
+            * self.crafts_per_minute
+    }
+
+    pub fn production_of(&self, fuel: Resource) -> f32 {
+        self.production.get(&fuel).copied().unwrap_or_default()
+    }
+
+    pub fn total_production(&self) -> f32 {
+        all_fuel()
+            .into_iter()
+            .map(|fuel| self.production_of(fuel))
+            .sum()
+    }
+
+    /// How `demand` is drawn from each fuel, in proportion to how much of it you produce. With
+    /// nothing produced yet it's split evenly.
+    pub fn split(&self, demand: f32) -> Vec<(Resource, f32)> {
+        let fuels = all_fuel();
+        let production = self.total_production();
+        fuels
+            .iter()
+            .map(|fuel| {
+                let share = if production > 0.0 {
+                    self.production_of(*fuel) / production
+                } else {
+                    1.0 / fuels.len() as f32
+                };
+                (*fuel, demand * share)
+            })
+            .collect()
+    }
+}
+
+// wood is also a plain ingredient, a slot only burns fuel when any fuel will do.
+pub fn is_fuel_slot(ingredient_slot: &[Resource]) -> bool {
+    all_fuel().iter().all(|fuel| ingredient_slot.contains(fuel))
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    io::{Error, ErrorKind, Write},
+    path::PathBuf,
+};
+
+use crate::{config_dir, titleize, Biome, Building, Moment, Need, Species};
+
+const HISTORY_VAR: &str = "FOR_THE_QUEEN_HISTORY";
+
+pub fn all_outcomes() -> Vec<Outcome> {
+    vec![Outcome::Victory, Outcome::Defeat, Outcome::Abandoned]
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Outcome {
+    Victory,
+    Defeat,
+    Abandoned,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Run {
+    pub species: Vec<Species>,
+    pub biome: Option<Biome>,
+    pub blueprints: Vec<Building>,
+    /// Needs that got met, with the year and season it first happened.
+    pub satisfied: Vec<(Need, Moment)>,
+    pub unmet: Vec<Need>,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Default)]
+pub struct RunHistory {
+    pub runs: Vec<Run>,
+    /// Lines of the log that couldn't be read, e.g. written by an older version.
+    pub skipped: usize,
+}
+
+impl RunHistory {
+    /// `FOR_THE_QUEEN_HISTORY`, or `for-the-queen/runs.jsonl` in the user's config directory.
+    pub fn path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var(HISTORY_VAR) {
+            return Some(PathBuf::from(path));
+        }
+        config_dir().map(|dir| dir.join("runs.jsonl"))
+    }
+
+    pub fn load() -> Result<Self, Error> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+        let mut history = Self::default();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str(line) {
+                Ok(run) => history.runs.push(run),
+                Err(_) => history.skipped += 1,
+            }
+        }
+        Ok(history)
+    }
+
+    /// Appends one line to the log, earlier runs are never rewritten.
+    pub fn append(run: &Run) -> Result<(), Error> {
+        let path = Self::path().ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                "there is no config directory to save runs in",
+            )
+        })?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", serde_json::to_string(run)?)
+    }
+
+    /// Victories and runs played for every lineup, most played first.
+    pub fn win_rates(&self) -> Vec<(Vec<Species>, usize, usize)> {
+        let mut lineups: BTreeMap<Vec<Species>, (usize, usize)> = BTreeMap::new();
+        for run in &self.runs {
+            let mut species = run.species.clone();
+            species.sort();
+            let (wins, played) = lineups.entry(species).or_default();
+            *played += 1;
+            if run.outcome == Outcome::Victory {
+                *wins += 1;
+            }
+        }
+        let mut win_rates: Vec<(Vec<Species>, usize, usize)> = lineups
+            .into_iter()
+            .map(|(species, (wins, played))| (species, wins, played))
+            .collect();
+        win_rates.sort_by_key(|(_, _, played)| std::cmp::Reverse(*played));
+        win_rates
+    }
+
+    /// How many runs ended with each need still unmet, most common first.
+    pub fn missing_needs(&self) -> Vec<(Need, usize)> {
+        let mut counter: BTreeMap<Need, usize> = BTreeMap::new();
+        for need in self.runs.iter().flat_map(|run| &run.unmet) {
+            *counter.entry(*need).or_default() += 1;
+        }
+        let mut missing: Vec<(Need, usize)> = counter.into_iter().collect();
+        missing.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        missing
+    }
+
+    /// Average seasons into the run it took to first meet each need, over the runs that met it.
+    pub fn average_time_to_satisfy(&self) -> Vec<(Need, f32)> {
+        let mut times: BTreeMap<Need, Vec<u32>> = BTreeMap::new();
+        for (need, moment) in self.runs.iter().flat_map(|run| &run.satisfied) {
+            times.entry(*need).or_default().push(moment.seasons_in());
+        }
+        times
+            .into_iter()
+            .map(|(need, seasons)| {
+                let total: u32 = seasons.iter().sum();
+                (need, total as f32 / seasons.len() as f32)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{Modifiers, Need, Resource, Species};
+
+#[derive(Debug, Default)]
+pub struct Inventory {
+    pub stock: HashMap<Resource, u32>,
+    /// How many goods each species eats through per minute for every need they have.
+    pub consumption: BTreeMap<Species, f32>,
+}
+
+#[derive(Debug)]
+pub struct Forecast {
+    pub need: Need,
+    pub stock: u32,
+    pub rate: f32,
+    /// `None` when nobody is consuming it, so it never runs out.
+    pub minutes_left: Option<f32>,
+}
+
+impl Forecast {
+    pub fn runs_out_before(&self, minutes: u32) -> bool {
+        self.minutes_left
+            .is_some_and(|minutes_left| minutes_left < minutes as f32)
+    }
+}
+
+impl Inventory {
+    pub fn stock_of(&self, good: Resource) -> u32 {
+        self.stock.get(&good).copied().unwrap_or_default()
+    }
+
+    pub fn consumption_of(&self, species: Species) -> f32 {
+        self.consumption.get(&species).copied().unwrap_or_default()
+    }
+
+    pub fn forecast(&self, selected_species: &[Species], modifiers: &Modifiers) -> Vec<Forecast> {
+        let mut rates: BTreeMap<Need, f32> = BTreeMap::new();
+        selected_species.iter().for_each(|species| {
+            modifiers.needs(*species).iter().for_each(|need| {
+                *rates.entry(*need).or_default() +=
+                    self.consumption_of(*species) * modifiers.consumption_rate();
+            })
+        });
+
+        rates
+            .into_iter()
+            .filter(|(need, _)| !modifiers.goods(need).is_empty())
+            .map(|(need, rate)| {
+                let stock = modifiers
+                    .goods(&need)
+                    .iter()
+                    .map(|good| self.stock_of(*good))
+                    .sum();
+                let minutes_left = if rate > 0.0 {
+                    Some(stock as f32 / rate)
+                } else {
+                    None
+                };
+                Forecast {
+                    need,
+                    stock,
+                    rate,
+                    minutes_left,
+                }
+            })
+            .collect()
+    }
+}
@@ -1,18 +1,62 @@
+mod biome;
+mod building;
+mod compatibility;
+mod config;
+mod difficulty;
 mod economy;
+mod embark;
+mod glade;
+mod hearth;
+mod history;
+mod inventory;
 mod menu;
+mod modifiers;
 mod needs;
+mod orders;
+mod profile;
+mod rainpunk;
 mod recipe;
+mod resolve;
 pub mod resource;
 mod screen;
+mod season;
+mod session;
+mod share;
 mod species;
+mod theme;
+mod timeline;
+mod trade;
+mod workers;
 
+pub use biome::*;
+pub use building::*;
+pub use compatibility::*;
+pub use config::*;
+pub use difficulty::*;
 pub use economy::*;
+pub use embark::*;
+pub use glade::*;
+pub use hearth::*;
+pub use history::*;
+pub use inventory::*;
 pub use menu::*;
+pub use modifiers::*;
 pub use needs::*;
+pub use orders::*;
+pub use profile::*;
+pub use rainpunk::*;
 pub use recipe::*;
+pub use resolve::*;
 use resource::*;
 pub use screen::*;
+pub use season::*;
+pub use session::*;
+pub use share::*;
 pub use species::*;
+pub use theme::*;
+pub use timeline::*;
+pub use trade::*;
+pub use workers::*;
 
 use convert_case::{Case, Casing};
 
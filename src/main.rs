@@ -1,9 +1,13 @@
-use for_the_queen_cli::{clear_screen, restore_cursor, Economy};
+use for_the_queen_cli::{clear_screen, restore_cursor, Config, Session};
 use inquire::InquireError;
 
 fn main() {
-    let mut economy = Economy::default();
-    let result = economy.plan();
+    let config = match Config::from_env() {
+        Ok(config) => config,
+        Err(e) => exit(Err(InquireError::Custom(Box::new(e)))),
+    };
+    let mut session = Session::new(config);
+    let result = session.plan();
     exit(result)
 }
 
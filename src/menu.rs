@@ -1,10 +1,12 @@
-use inquire::{InquireError, MultiSelect, Select};
-use std::fmt::Display;
+use inquire::{Confirm, CustomType, InquireError, MultiSelect, Select, Text};
+use std::fmt::{self, Display};
+
+use crate::titleize;
 
 #[derive(Debug)]
 pub struct MultiSelectMenu<T: Display + Copy> {
     title: String,
-    options: Vec<(T, Checkbox)>,
+    options: Vec<(T, Availability)>,
 }
 
 impl<T: Display + Copy + PartialEq> MultiSelectMenu<T> {
@@ -13,7 +15,7 @@ impl<T: Display + Copy + PartialEq> MultiSelectMenu<T> {
             title: title.to_string(),
             options: options
                 .into_iter()
-                .map(|element| (element, Checkbox::Unchecked))
+                .map(|element| (element, Availability::Unavailable))
                 .collect(),
         }
     }
@@ -43,8 +45,8 @@ impl<T: Display + Copy + PartialEq> MultiSelectMenu<T> {
     pub fn get_selections(&self) -> Vec<T> {
         self.options
             .iter()
-            .filter_map(|(option, checkbox)| {
-                if let Checkbox::Checked = checkbox {
+            .filter_map(|(option, availability)| {
+                if availability.is_available() {
                     Some(*option)
                 } else {
                     None
@@ -53,22 +55,51 @@ impl<T: Display + Copy + PartialEq> MultiSelectMenu<T> {
             .collect()
     }
 
+    pub fn get_availability_strings(&self) -> Vec<(String, Availability)> {
+        self.options
+            .iter()
+            .filter(|(_, availability)| *availability != Availability::Unavailable)
+            .map(|(option, availability)| (option.to_string(), *availability))
+            .collect()
+    }
+
+    pub fn get_availability(&self, option: T) -> Option<Availability> {
+        self.options
+            .iter()
+            .find(|(candidate, _)| *candidate == option)
+            .map(|(_, availability)| *availability)
+    }
+
+    pub fn set_availability(&mut self, option: T, availability: Availability) {
+        if let Some((_, current)) = self
+            .options
+            .iter_mut()
+            .find(|(candidate, _)| *candidate == option)
+        {
+            *current = availability;
+        }
+    }
+
     pub fn select(&mut self, selected_options: Vec<T>) {
-        self.options.iter_mut().for_each(|(option, checkbox)| {
-            *checkbox = if selected_options.contains(&option) {
-                Checkbox::Checked
+        self.options.iter_mut().for_each(|(option, availability)| {
+            *availability = if selected_options.contains(option) {
+                Availability::Produced
             } else {
-                Checkbox::Unchecked
+                Availability::Unavailable
             };
         });
     }
 
+    // ticking a good keeps whatever finer grained availability it already had. planned and
+    // depleted goods show up unticked, so leaving them unticked keeps them as they were.
     fn stringly_select(&mut self, selected_options: Vec<String>) {
-        self.options.iter_mut().for_each(|(option, checkbox)| {
-            *checkbox = if selected_options.contains(&option.to_string()) {
-                Checkbox::Checked
-            } else {
-                Checkbox::Unchecked
+        self.options.iter_mut().for_each(|(option, availability)| {
+            let selected = selected_options.contains(&option.to_string());
+            *availability = match (selected, availability.is_available()) {
+                (true, true) => *availability,
+                (true, false) => Availability::Produced,
+                (false, true) => Availability::Unavailable,
+                (false, false) => *availability,
             };
         });
     }
@@ -78,8 +109,8 @@ impl<T: Display + Copy + PartialEq> MultiSelectMenu<T> {
         let mut selected_indexes = Vec::new();
         let mut options = Vec::new();
 
-        for (i, (option, checkbox)) in self.options.iter().enumerate() {
-            if let Checkbox::Checked = *checkbox {
+        for (i, (option, availability)) in self.options.iter().enumerate() {
+            if availability.is_available() {
                 is_empty = false;
                 selected_indexes.push(i);
             }
@@ -100,10 +131,38 @@ pub struct MenuView {
     pub is_empty: bool,
 }
 
-#[derive(Debug)]
-enum Checkbox {
-    Checked,
-    Unchecked,
+pub fn all_availabilities() -> Vec<Availability> {
+    vec![
+        Availability::Produced,
+        Availability::Traded,
+        Availability::Stockpiled,
+        Availability::Planned,
+        Availability::Depleted,
+        Availability::Unavailable,
+    ]
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Availability {
+    Unavailable,
+    Produced,
+    Traded,
+    Stockpiled,
+    Planned,
+    Depleted,
+}
+
+impl Availability {
+    /// Stockpiles count as available for now, they just won't last.
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::Produced | Self::Traded | Self::Stockpiled)
+    }
+}
+
+impl Display for Availability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
 }
 
 #[derive(Debug)]
@@ -141,3 +200,25 @@ impl<T: Display + Copy> SingleSelectMenu<T> {
         }
     }
 }
+
+pub fn prompt_number<T>(title: &str, current: T) -> Result<T, InquireError>
+where
+    T: Clone + Display + std::str::FromStr,
+{
+    CustomType::new(title)
+        .with_default(current)
+        .with_error_message("Please type a number")
+        .prompt()
+}
+
+pub fn prompt_text(title: &str) -> Result<String, InquireError> {
+    Text::new(title).prompt()
+}
+
+pub fn confirm(title: &str, default: bool) -> Result<bool, InquireError> {
+    Confirm::new(title).with_default(default).prompt()
+}
+
+pub fn pause() -> Result<(), InquireError> {
+    Text::new("Press enter to go back").prompt().map(|_| ())
+}
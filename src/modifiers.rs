@@ -0,0 +1,212 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    religion, resource::*, titleize, Difficulty, DifficultyEffect, Need, Recipe, Resource, Species,
+};
+
+/// Blueprints offered by each draft before the difficulty takes any away.
+pub const BLUEPRINT_CHOICES: u32 = 4;
+
+pub fn all_perks() -> Vec<Perk> {
+    vec![
+        Perk::ForagersPorridge,
+        Perk::JerkyPies,
+        Perk::ClayShortage,
+        Perk::HarpyGourmets,
+        Perk::LizardPorridge,
+        Perk::FreeSermons,
+    ]
+}
+
+/// Cornerstones and other perks picked up during a run that change the economy.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Perk {
+    ForagersPorridge,
+    JerkyPies,
+    ClayShortage,
+    HarpyGourmets,
+    LizardPorridge,
+    FreeSermons,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Effect {
+    /// Another good is accepted in one of the ingredient slots of `target`.
+    AddOption {
+        target: Need,
+        slot: usize,
+        option: Resource,
+    },
+    /// A good is no longer accepted anywhere in the recipe of `target`.
+    RemoveOption {
+        target: Need,
+        option: Resource,
+    },
+    AddNeed {
+        species: Species,
+        need: Need,
+    },
+    RemoveNeed {
+        species: Species,
+        need: Need,
+    },
+    /// Nobody needs goods for this anymore.
+    SatisfyNeed(Need),
+}
+
+impl Perk {
+    pub fn effects(&self) -> Vec<Effect> {
+        match self {
+            Self::ForagersPorridge => vec![Effect::AddOption {
+                target: Need::from(porridge()),
+                slot: 0,
+                option: roots(),
+            }],
+            Self::JerkyPies => vec![Effect::AddOption {
+                target: Need::from(pie()),
+                slot: 1,
+                option: jerky(),
+            }],
+            Self::ClayShortage => vec![Effect::RemoveOption {
+                target: Need::from(bricks()),
+                option: clay(),
+            }],
+            Self::HarpyGourmets => vec![
+                Effect::RemoveNeed {
+                    species: Species::Harpies,
+                    need: Need::from(paste()),
+                },
+                Effect::AddNeed {
+                    species: Species::Harpies,
+                    need: Need::from(skewers()),
+                },
+            ],
+            Self::LizardPorridge => vec![Effect::AddNeed {
+                species: Species::Lizards,
+                need: Need::from(porridge()),
+            }],
+            Self::FreeSermons => vec![Effect::SatisfyNeed(religion())],
+        }
+    }
+}
+
+impl Display for Perk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+/// The catalog as changed by the perks and difficulty in play. Planners read recipes and needs
+/// through this.
+#[derive(Debug, Default, Clone)]
+pub struct Modifiers {
+    pub perks: Vec<Perk>,
+    pub difficulty: Difficulty,
+}
+
+impl Modifiers {
+    fn effects(&self) -> impl Iterator<Item = Effect> + '_ {
+        self.perks.iter().flat_map(|perk| perk.effects())
+    }
+
+    pub fn recipe(&self, good: &(impl Recipe + Display)) -> Vec<Vec<Resource>> {
+        let name = good.to_string();
+        let mut recipe = good.recipe();
+        for effect in self.effects() {
+            match effect {
+                Effect::AddOption {
+                    target,
+                    slot,
+                    option,
+                } if target.to_string() == name => {
+                    if let Some(ingredient_slot) = recipe.get_mut(slot) {
+                        if !ingredient_slot.contains(&option) {
+                            ingredient_slot.push(option);
+                        }
+                    }
+                }
+                Effect::RemoveOption { target, option } if target.to_string() == name => {
+                    recipe
+                        .iter_mut()
+                        .for_each(|ingredient_slot| ingredient_slot.retain(|good| *good != option));
+                }
+                _ => {}
+            }
+        }
+        recipe
+    }
+
+    pub fn needs(&self, species: Species) -> Vec<Need> {
+        let mut needs = species.needs();
+        for effect in self.effects() {
+            match effect {
+                Effect::AddNeed {
+                    species: affected,
+                    need,
+                } if affected == species && !needs.contains(&need) => needs.push(need),
+                Effect::RemoveNeed {
+                    species: affected,
+                    need,
+                } if affected == species => needs.retain(|existing| *existing != need),
+                Effect::SatisfyNeed(need) => needs.retain(|existing| *existing != need),
+                _ => {}
+            }
+        }
+        needs
+    }
+
+    pub fn consumption_rate(&self) -> f32 {
+        self.difficulty
+            .effects()
+            .iter()
+            .map(|effect| match effect {
+                DifficultyEffect::ConsumptionRate(rate) => *rate,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    pub fn fuel_burn(&self) -> f32 {
+        self.difficulty
+            .effects()
+            .iter()
+            .map(|effect| match effect {
+                DifficultyEffect::FuelBurn(burn) => *burn,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    /// How many blueprints a draft offers to choose from.
+    pub fn blueprint_choices(&self) -> u32 {
+        let fewer: u32 = self
+            .difficulty
+            .effects()
+            .iter()
+            .map(|effect| match effect {
+                DifficultyEffect::FewerBlueprints(fewer) => *fewer,
+                _ => 0,
+            })
+            .sum();
+        BLUEPRINT_CHOICES.saturating_sub(fewer).max(1)
+    }
+
+    pub fn resolve_penalty(&self) -> u32 {
+        self.difficulty
+            .effects()
+            .iter()
+            .map(|effect| match effect {
+                DifficultyEffect::ResolvePenalty(penalty) => *penalty,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Like [`Need::goods`], but services take whatever their modified recipe accepts.
+    pub fn goods(&self, need: &Need) -> Vec<Resource> {
+        match need {
+            Need::Service(_) => self.recipe(need).concat(),
+            _ => need.goods(),
+        }
+    }
+}
@@ -9,7 +9,23 @@ pub enum Need {
     Clothing(Clothing),
     ComplexFood(ComplexFood),
     Service(Service),
+    Housing(Housing),
     BuildingMaterial(BuildingMaterial),
+    /// Any other good that's asked for, e.g. by an order.
+    Good(Resource),
+}
+
+impl From<Resource> for Need {
+    fn from(good: Resource) -> Self {
+        match good {
+            Resource::Clothing(clothing) => Self::Clothing(clothing),
+            Resource::ComplexFood(complex_food) => Self::ComplexFood(complex_food),
+            Resource::BuildingMaterial(building_material) => {
+                Self::BuildingMaterial(building_material)
+            }
+            _ => Self::Good(good),
+        }
+    }
 }
 
 impl Recipe for Need {
@@ -18,7 +34,35 @@ impl Recipe for Need {
             Self::Clothing(clothing) => clothing.recipe(),
             Self::ComplexFood(complex_food) => complex_food.recipe(),
             Self::Service(service) => service.recipe(),
+            Self::Housing(housing) => housing.recipe(),
             Self::BuildingMaterial(building_material) => building_material.recipe(),
+            Self::Good(good) => good.recipe(),
+        }
+    }
+}
+
+impl Need {
+    /// How much resolve a species gains when this need is met.
+    pub fn resolve(&self) -> u32 {
+        match self {
+            Self::ComplexFood(_) | Self::Clothing(_) | Self::Service(_) => 1,
+            Self::Housing(_) => 2,
+            Self::BuildingMaterial(_) | Self::Good(_) => 0,
+        }
+    }
+
+    /// The goods that get consumed to satisfy this need.
+    pub fn goods(&self) -> Vec<Resource> {
+        match self {
+            Self::Clothing(clothing) => vec![Resource::Clothing(*clothing)],
+            Self::ComplexFood(complex_food) => vec![Resource::ComplexFood(*complex_food)],
+            Self::Service(service) => service.recipe().concat(),
+            // houses are built, not consumed
+            Self::Housing(_) => vec![],
+            Self::BuildingMaterial(building_material) => {
+                vec![Resource::BuildingMaterial(*building_material)]
+            }
+            Self::Good(good) => vec![*good],
         }
     }
 }
@@ -32,7 +76,9 @@ impl Display for Need {
                 Self::Clothing(clothing) => clothing.to_string(),
                 Self::ComplexFood(complex_food) => complex_food.to_string(),
                 Self::Service(service) => service.to_string(),
+                Self::Housing(housing) => housing.to_string(),
                 Self::BuildingMaterial(building_material) => building_material.to_string(),
+                Self::Good(good) => good.to_string(),
             }
         )
     }
@@ -91,13 +137,51 @@ pub fn brawling() -> Need {
 
 impl Recipe for Service {
     fn recipe(&self) -> Vec<Vec<Resource>> {
-        vec![vec![match self {
-            Self::Education => scrolls(),
-            Self::Religion => incense(),
-            Self::Treatment => tea(),
-            Self::Luxury => wine(),
-            Self::Leisure => ale(),
-            Self::Brawling => training_gear(),
-        }]]
+        vec![match self {
+            Self::Education => vec![scrolls()],
+            Self::Religion => vec![incense(), scrolls()],
+            Self::Treatment => vec![tea()],
+            Self::Luxury => vec![wine(), incense()],
+            Self::Leisure => vec![ale(), wine()],
+            Self::Brawling => vec![training_gear(), ale()],
+        }]
+    }
+}
+
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Housing {
+    BeaverHouse,
+    HumanHouse,
+    LizardHouse,
+    HarpyHouse,
+    FoxHouse,
+}
+
+impl Display for Housing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+pub fn all_housing() -> Vec<Housing> {
+    vec![
+        Housing::BeaverHouse,
+        Housing::HumanHouse,
+        Housing::LizardHouse,
+        Housing::HarpyHouse,
+        Housing::FoxHouse,
+    ]
+}
+
+/// A house takes every slot, since it's built from all of its materials at once.
+impl Recipe for Housing {
+    fn recipe(&self) -> Vec<Vec<Resource>> {
+        match self {
+            Self::BeaverHouse => vec![vec![planks()], vec![fabric()]],
+            Self::HumanHouse => vec![vec![planks()], vec![bricks()]],
+            Self::LizardHouse => vec![vec![bricks()], vec![fabric()]],
+            Self::HarpyHouse => vec![vec![planks()], vec![fabric()], vec![bricks()]],
+            Self::FoxHouse => vec![vec![planks()], vec![bricks(), fabric()]],
+        }
     }
 }
@@ -0,0 +1,75 @@
+use std::fmt::{self, Display};
+
+use crate::{titleize, Availability, Inventory, Resource};
+
+pub fn all_goals() -> Vec<Goal> {
+    vec![Goal::Deliver, Goal::Produce]
+}
+
+/// What an order asks of a good.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Goal {
+    /// Have the quantity in the warehouse.
+    Deliver,
+    /// Have the good in production, with the quantity already made.
+    Produce,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Order {
+    pub good: Resource,
+    pub quantity: u32,
+    pub goal: Goal,
+}
+
+impl Order {
+    /// How much of the order the warehouse could already cover.
+    pub fn progress(&self, inventory: &Inventory) -> u32 {
+        inventory.stock_of(self.good).min(self.quantity)
+    }
+
+    /// How many more are wanted, a production order still wants one until the good is produced.
+    pub fn remaining(&self, inventory: &Inventory, availability: Option<Availability>) -> u32 {
+        let remaining = self.quantity - self.progress(inventory);
+        match self.goal {
+            Goal::Produce if availability != Some(Availability::Produced) => remaining.max(1),
+            _ => remaining,
+        }
+    }
+
+    pub fn is_fulfilled(&self, inventory: &Inventory, availability: Option<Availability>) -> bool {
+        self.remaining(inventory, availability) == 0
+    }
+}
+
+impl Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.goal {
+            Goal::Deliver => write!(f, "{} {}", self.quantity, self.good),
+            Goal::Produce => write!(f, "{} {} producing", self.quantity, self.good),
+        }
+    }
+}
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Orders {
+    pub active: Vec<Order>,
+}
+
+impl Orders {
+    pub fn add(&mut self, order: Order) {
+        self.active.push(order);
+    }
+
+    pub fn complete(&mut self, order: Order) {
+        if let Some(i) = self.active.iter().position(|active| *active == order) {
+            self.active.remove(i);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use std::{
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    path::PathBuf,
+};
+
+use crate::{
+    all_buildings, all_service_buildings, all_species, config_dir, titleize, Building, Embark,
+    EmbarkOption, ServiceBuilding, Species, DEFAULT_EMBARK_POINTS,
+};
+
+const PROFILE_VAR: &str = "FOR_THE_QUEEN_PROFILE";
+
+pub fn all_upgrades() -> Vec<Upgrade> {
+    vec![Upgrade::RainEngines, Upgrade::ExtraEmbarkPoints]
+}
+
+/// Smoldering City upgrades that change what a run can use.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Upgrade {
+    RainEngines,
+    ExtraEmbarkPoints,
+}
+
+impl Display for Upgrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+/// What a player has unlocked in the meta-progression, kept between sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub species: Vec<Species>,
+    pub buildings: Vec<Building>,
+    pub service_buildings: Vec<ServiceBuilding>,
+    pub upgrades: Vec<Upgrade>,
+}
+
+/// Without a profile everything is unlocked.
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            species: all_species(),
+            buildings: all_buildings(),
+            service_buildings: all_service_buildings(),
+            upgrades: all_upgrades(),
+        }
+    }
+}
+
+impl Profile {
+    /// `FOR_THE_QUEEN_PROFILE`, or `for-the-queen/profile.json` in the user's config directory.
+    pub fn path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var(PROFILE_VAR) {
+            return Some(PathBuf::from(path));
+        }
+        config_dir().map(|dir| dir.join("profile.json"))
+    }
+
+    pub fn load() -> Result<Self, Error> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                let profile: Self = serde_json::from_str(&contents)?;
+                if profile.species.is_empty() || profile.buildings.is_empty() {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "{} has to unlock at least one species and one blueprint",
+                            path.display()
+                        ),
+                    ));
+                }
+                Ok(profile)
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let path = Self::path().ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                "there is no config directory to save the profile in",
+            )
+        })?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn has_upgrade(&self, upgrade: Upgrade) -> bool {
+        self.upgrades.contains(&upgrade)
+    }
+
+    pub fn embark_points(&self) -> u32 {
+        if self.has_upgrade(Upgrade::ExtraEmbarkPoints) {
+            DEFAULT_EMBARK_POINTS + 2
+        } else {
+            DEFAULT_EMBARK_POINTS
+        }
+    }
+
+    /// Goods and effects can always be brought along, blueprints only once they're unlocked.
+    pub fn allows(&self, option: &EmbarkOption) -> bool {
+        match option.embark {
+            Embark::Goods(..) | Embark::Effect(_) => true,
+            Embark::Blueprint(building) => self.buildings.contains(&building),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use crate::{
+    all_seasons, is_fuel_slot, purging_fire, titleize, BuildQueue, Building, Modifiers, Season,
+};
+
+pub const MAX_ENGINE_LEVEL: u8 = 3;
+
+// rough per minute figures, each engine level burns more water for a bigger boost
+const WATER_PER_LEVEL: f32 = 0.5;
+const BOOST_PER_LEVEL: f32 = 0.25;
+const CYSTS_PER_WATER: f32 = 0.1;
+/// Purging Fire a Blight Post burns to clear a single cyst.
+const FIRE_PER_CYST: f32 = 1.0;
+
+#[derive(Debug, Default)]
+pub struct RainEngines {
+    pub levels: BTreeMap<Building, u8>,
+}
+
+impl RainEngines {
+    pub fn level(&self, building: Building) -> u8 {
+        self.levels.get(&building).copied().unwrap_or_default()
+    }
+
+    pub fn set_level(&mut self, building: Building, level: u8) {
+        if level == 0 {
+            self.levels.remove(&building);
+        } else {
+            self.levels.insert(building, level.min(MAX_ENGINE_LEVEL));
+        }
+    }
+
+    /// Water burned per minute by the engines in `building`.
+    pub fn consumption(&self, building: Building) -> f32 {
+        self.level(building) as f32 * WATER_PER_LEVEL
+    }
+
+    /// Extra production speed, 0.5 means 50% faster.
+    pub fn boost(&self, building: Building) -> f32 {
+        self.level(building) as f32 * BOOST_PER_LEVEL
+    }
+
+    /// Water burned per minute by the engines of every queued building, by the season that water
+    /// is collected in. Engines only run in the buildings the plan actually has.
+    pub fn water_demand(&self, queue: &BuildQueue) -> Vec<(Season, f32)> {
+        all_seasons()
+            .into_iter()
+            .map(|season| {
+                let demand = self
+                    .levels
+                    .keys()
+                    .filter(|building| building.engine_water() == Some(season))
+                    .map(|building| self.consumption(*building) * queue.count(*building) as f32)
+                    .sum();
+                (season, demand)
+            })
+            .collect()
+    }
+
+    pub fn cysts_per_minute(&self, queue: &BuildQueue) -> f32 {
+        self.water_demand(queue)
+            .iter()
+            .map(|(_, demand)| demand * CYSTS_PER_WATER)
+            .sum()
+    }
+
+    pub fn blightrot_risk(&self, queue: &BuildQueue) -> BlightrotRisk {
+        match self.cysts_per_minute(queue) {
+            cysts if cysts <= 0.0 => BlightrotRisk::None,
+            cysts if cysts < 0.15 => BlightrotRisk::Low,
+            cysts if cysts < 0.3 => BlightrotRisk::Moderate,
+            _ => BlightrotRisk::High,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BlightrotRisk {
+    None,
+    Low,
+    Moderate,
+    High,
+}
+
+impl Display for BlightrotRisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+/// Blightrot cysts already in the settlement, the engines keep growing more.
+#[derive(Debug, Default)]
+pub struct Blight {
+    pub cysts: u32,
+}
+
+impl Blight {
+    pub fn cysts_within(&self, engines: &RainEngines, queue: &BuildQueue, minutes: u32) -> f32 {
+        self.cysts as f32 + engines.cysts_per_minute(queue) * minutes as f32
+    }
+
+    /// Purging Fire needed to clear every cyst there will be within `minutes`.
+    pub fn fire_needed(&self, engines: &RainEngines, queue: &BuildQueue, minutes: u32) -> f32 {
+        self.cysts_within(engines, queue, minutes) * FIRE_PER_CYST
+    }
+
+    /// Fuel burned per minute crafting Purging Fire for the cysts the engines grow.
+    pub fn fuel_per_minute(
+        &self,
+        engines: &RainEngines,
+        queue: &BuildQueue,
+        modifiers: &Modifiers,
+    ) -> f32 {
+        engines.cysts_per_minute(queue) * FIRE_PER_CYST * fuel_per_fire(modifiers)
+    }
+
+    /// Fuel burned per minute to clear the cysts already there within `minutes`.
+    pub fn existing_fuel_per_minute(&self, minutes: u32, modifiers: &Modifiers) -> f32 {
+        self.cysts as f32 * FIRE_PER_CYST * fuel_per_fire(modifiers) / minutes.max(1) as f32
+    }
+
+    /// Fuel it takes to make all the Purging Fire needed within `minutes`.
+    pub fn fuel_needed(
+        &self,
+        engines: &RainEngines,
+        queue: &BuildQueue,
+        minutes: u32,
+        modifiers: &Modifiers,
+    ) -> f32 {
+        self.fire_needed(engines, queue, minutes) * fuel_per_fire(modifiers)
+    }
+}
+
+fn fuel_per_fire(modifiers: &Modifiers) -> f32 {
+    modifiers
+        .recipe(&purging_fire())
+        .iter()
+        .filter(|ingredient_slot| is_fuel_slot(ingredient_slot))
+        .count() as f32
+}
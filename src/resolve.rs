@@ -0,0 +1,32 @@
+use crate::{Modifiers, Need, Species};
+
+/// How content a species is, going by which of its needs are met.
+#[derive(Debug)]
+pub struct Resolve {
+    pub species: Species,
+    pub met: Vec<Need>,
+    pub unmet: Vec<Need>,
+    /// Resolve lost to the difficulty.
+    pub penalty: u32,
+}
+
+impl Resolve {
+    pub fn of(species: Species, modifiers: &Modifiers, is_met: impl Fn(&Need) -> bool) -> Self {
+        let (met, unmet) = modifiers.needs(species).into_iter().partition(is_met);
+        Self {
+            species,
+            met,
+            unmet,
+            penalty: modifiers.resolve_penalty(),
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        self.met.iter().map(Need::resolve).sum::<u32>() as i32 - self.penalty as i32
+    }
+
+    /// The score with every need met.
+    pub fn max(&self) -> i32 {
+        self.score() + self.unmet.iter().map(Need::resolve).sum::<u32>() as i32
+    }
+}
@@ -4,7 +4,7 @@ use std::fmt::{self, Display};
 
 use crate::{titleize, Recipe};
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum Resource {
     Fuel(Fuel),
     CraftingResource(CraftingResource),
@@ -33,6 +33,21 @@ impl Display for Resource {
     }
 }
 
+impl Resource {
+    /// Roughly what a trader pays for one unit, in amber.
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Fuel(fuel) => fuel.trade_value(),
+            Self::CraftingResource(crafting_resource) => crafting_resource.trade_value(),
+            Self::BuildingMaterial(building_material) => building_material.trade_value(),
+            Self::ConsumableItem(consumable_item) => consumable_item.trade_value(),
+            Self::SimpleFood(simple_food) => simple_food.trade_value(),
+            Self::ComplexFood(complex_food) => complex_food.trade_value(),
+            Self::Clothing(clothing) => clothing.trade_value(),
+        }
+    }
+}
+
 impl Recipe for Resource {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -60,7 +75,7 @@ pub fn all_goods() -> Vec<Resource> {
     .concat()
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum Fuel {
     Oil,
     Coal,
@@ -74,6 +89,16 @@ impl Display for Fuel {
     }
 }
 
+impl Fuel {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Wood => 0.1,
+            Self::Oil | Self::Coal => 0.3,
+            Self::SeaMarrow => 0.4,
+        }
+    }
+}
+
 impl Recipe for Fuel {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -102,7 +127,7 @@ pub fn all_fuel() -> Vec<Resource> {
     vec![oil(), coal(), sea_marrow(), wood()]
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum CraftingResource {
     Pottery,
     Waterskins,
@@ -126,6 +151,7 @@ pub enum CraftingResource {
     Clay,
     Salt,
     CopperOre,
+    PurgingFire,
 }
 
 impl Display for CraftingResource {
@@ -134,6 +160,28 @@ impl Display for CraftingResource {
     }
 }
 
+impl CraftingResource {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::DrizzleWater | Self::StormWater | Self::ClearanceWater => 0.05,
+            Self::Algae
+            | Self::PlantFiber
+            | Self::Reed
+            | Self::Grain
+            | Self::Stones
+            | Self::Clay
+            | Self::Salt
+            | Self::Resin
+            | Self::Herbs => 0.15,
+            Self::Scales | Self::CopperOre | Self::Leather => 0.25,
+            Self::Flour | Self::Dye => 0.35,
+            Self::Pottery | Self::Waterskins | Self::Barrels => 0.5,
+            Self::CopperBars | Self::CrystallizedDew => 0.6,
+            Self::PurgingFire => 0.2,
+        }
+    }
+}
+
 impl Recipe for CraftingResource {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -154,6 +202,7 @@ impl Recipe for CraftingResource {
                 vec![storm_water(), drizzle_water(), clearance_water()],
             ],
             Self::Clay | Self::Reed | Self::Resin => vec![vec![clearance_water()]],
+            Self::PurgingFire => vec![vec![wood(), oil(), coal(), sea_marrow()]],
             Self::DrizzleWater
             | Self::StormWater
             | Self::ClearanceWater
@@ -256,6 +305,10 @@ pub fn copper_ore() -> Resource {
     Resource::CraftingResource(CraftingResource::CopperOre)
 }
 
+pub fn purging_fire() -> Resource {
+    Resource::CraftingResource(CraftingResource::PurgingFire)
+}
+
 pub fn all_crafting_resources() -> Vec<Resource> {
     vec![
         pottery(),
@@ -280,6 +333,7 @@ pub fn all_crafting_resources() -> Vec<Resource> {
         clay(),
         salt(),
         copper_ore(),
+        purging_fire(),
     ]
 }
 
@@ -288,6 +342,7 @@ pub enum BuildingMaterial {
     Planks,
     Fabric,
     Bricks,
+    Parts,
 }
 
 impl Display for BuildingMaterial {
@@ -296,12 +351,23 @@ impl Display for BuildingMaterial {
     }
 }
 
+impl BuildingMaterial {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Planks => 0.25,
+            Self::Fabric | Self::Bricks => 0.35,
+            Self::Parts => 1.0,
+        }
+    }
+}
+
 impl Recipe for BuildingMaterial {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
             Self::Planks => vec![vec![wood()]],
             Self::Fabric => vec![vec![plant_fiber(), reed(), algae()]],
             Self::Bricks => vec![vec![clay(), stone()]],
+            Self::Parts => vec![vec![copper_bars(), crystallized_dew()]],
         }
     }
 }
@@ -318,11 +384,15 @@ pub fn bricks() -> Resource {
     Resource::BuildingMaterial(BuildingMaterial::Bricks)
 }
 
+pub fn parts() -> Resource {
+    Resource::BuildingMaterial(BuildingMaterial::Parts)
+}
+
 pub fn all_building_materials() -> Vec<Resource> {
-    vec![planks(), fabric(), bricks()]
+    vec![planks(), fabric(), bricks(), parts()]
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum ConsumableItem {
     Scrolls,
     Incense,
@@ -338,6 +408,16 @@ impl Display for ConsumableItem {
     }
 }
 
+impl ConsumableItem {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Ale | Self::Tea => 0.5,
+            Self::Incense | Self::Scrolls => 0.6,
+            Self::Wine | Self::TrainingGear => 0.7,
+        }
+    }
+}
+
 impl Recipe for ConsumableItem {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -394,7 +474,7 @@ pub fn all_consumable_items() -> Vec<Resource> {
     vec![scrolls(), incense(), training_gear(), wine(), ale(), tea()]
 }
 
-#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Ord, PartialOrd, Hash, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum SimpleFood {
     Mushrooms,
     Roots,
@@ -412,6 +492,16 @@ impl Display for SimpleFood {
     }
 }
 
+impl SimpleFood {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Roots | Self::Vegetables | Self::Berries | Self::Insects => 0.15,
+            Self::Mushrooms | Self::Fish | Self::Eggs => 0.2,
+            Self::Meat => 0.25,
+        }
+    }
+}
+
 impl Recipe for SimpleFood {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -492,6 +582,15 @@ pub fn all_clothing() -> Vec<Resource> {
     vec![coats(), boots()]
 }
 
+impl Clothing {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Boots => 0.6,
+            Self::Coats => 0.8,
+        }
+    }
+}
+
 impl Recipe for Clothing {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -558,6 +657,16 @@ pub fn all_complex_food() -> Vec<Resource> {
     ]
 }
 
+impl ComplexFood {
+    pub fn trade_value(&self) -> f32 {
+        match self {
+            Self::Porridge | Self::Skewers => 0.4,
+            Self::Biscuits | Self::Jerky | Self::Paste => 0.45,
+            Self::PickledGoods | Self::Pie => 0.55,
+        }
+    }
+}
+
 impl Recipe for ComplexFood {
     fn recipe(&self) -> Vec<Vec<Resource>> {
         match self {
@@ -4,10 +4,14 @@ use crossterm::{
     QueueableCommand,
 };
 
-use std::io::{stdout, Write};
+use std::io::{stdout, IsTerminal, Write};
 
+// captured output is kept free of escape codes so it can be pasted elsewhere
 pub fn clear_screen() {
     let mut out = stdout();
+    if !out.is_terminal() {
+        return;
+    }
     out.queue(Hide).unwrap();
     out.queue(Clear(ClearType::All)).unwrap();
     out.queue(MoveTo(0, 0)).unwrap();
@@ -16,6 +20,9 @@ pub fn clear_screen() {
 
 pub fn restore_cursor() {
     let mut out = stdout();
+    if !out.is_terminal() {
+        return;
+    }
     out.queue(Show).unwrap();
     out.flush().unwrap();
 }
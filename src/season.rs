@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use crate::{clearance_water, drizzle_water, storm_water, titleize, Modifiers, Recipe, Resource};
+
+pub fn all_seasons() -> Vec<Season> {
+    vec![Season::Drizzle, Season::Clearance, Season::Storm]
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Season {
+    Drizzle,
+    Clearance,
+    Storm,
+}
+
+impl Season {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Drizzle => Self::Clearance,
+            Self::Clearance => Self::Storm,
+            Self::Storm => Self::Drizzle,
+        }
+    }
+
+    /// The rain water that can only be collected during this season.
+    pub fn water(&self) -> Resource {
+        match self {
+            Self::Drizzle => drizzle_water(),
+            Self::Clearance => clearance_water(),
+            Self::Storm => storm_water(),
+        }
+    }
+
+    pub fn default_minutes(&self) -> u32 {
+        match self {
+            Self::Drizzle => 4,
+            Self::Clearance => 5,
+            Self::Storm => 2,
+        }
+    }
+
+    /// The season a good can only be made in, if any ingredient slot is nothing but that season's water.
+    pub fn locking(good: &(impl Recipe + Display), modifiers: &Modifiers) -> Option<Self> {
+        modifiers.recipe(good).iter().find_map(|ingredient_slot| {
+            let mut seasons = ingredient_slot.iter().map(|ingredient| {
+                all_seasons()
+                    .into_iter()
+                    .find(|season| season.water() == *ingredient)
+                    .or_else(|| Self::locking(ingredient, modifiers))
+            });
+            let first = seasons.next()??;
+            seasons.all(|season| season == Some(first)).then_some(first)
+        })
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+#[derive(Debug)]
+pub struct SeasonCycle {
+    pub year: u32,
+    pub current: Season,
+    pub elapsed_minutes: u32,
+    pub lengths: BTreeMap<Season, u32>,
+}
+
+impl Default for SeasonCycle {
+    fn default() -> Self {
+        Self {
+            year: 1,
+            current: Season::Drizzle,
+            elapsed_minutes: 0,
+            lengths: all_seasons()
+                .into_iter()
+                .map(|season| (season, season.default_minutes()))
+                .collect(),
+        }
+    }
+}
+
+impl SeasonCycle {
+    pub fn moment(&self) -> Moment {
+        Moment {
+            year: self.year,
+            season: self.current,
+        }
+    }
+
+    /// Minutes since the run began, assuming every year so far had the current season lengths.
+    pub fn minute(&self) -> u32 {
+        let year: u32 = all_seasons()
+            .iter()
+            .map(|season| self.length(*season))
+            .sum();
+        let earlier: u32 = all_seasons()
+            .iter()
+            .take_while(|season| **season != self.current)
+            .map(|season| self.length(*season))
+            .sum();
+        self.year.saturating_sub(1) * year + earlier + self.elapsed_minutes
+    }
+
+    pub fn length(&self, season: Season) -> u32 {
+        self.lengths
+            .get(&season)
+            .copied()
+            .unwrap_or_else(|| season.default_minutes())
+    }
+
+    pub fn minutes_left(&self) -> u32 {
+        self.length(self.current)
+            .saturating_sub(self.elapsed_minutes)
+    }
+
+    /// Minutes until `season` next begins, zero if it's already here.
+    pub fn minutes_until(&self, season: Season) -> u32 {
+        if season == self.current {
+            return 0;
+        }
+        let mut minutes = self.minutes_left();
+        let mut upcoming = self.current.next();
+        while upcoming != season {
+            minutes += self.length(upcoming);
+            upcoming = upcoming.next();
+        }
+        minutes
+    }
+}
+
+/// A point in the run, as precise as the game's calendar.
+#[derive(Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Moment {
+    pub year: u32,
+    pub season: Season,
+}
+
+impl Moment {
+    /// Seasons gone by since the first Drizzle.
+    pub fn seasons_in(&self) -> u32 {
+        let season = all_seasons()
+            .iter()
+            .position(|season| *season == self.season)
+            .unwrap_or_default() as u32;
+        self.year.saturating_sub(1) * all_seasons().len() as u32 + season
+    }
+}
+
+impl Display for Moment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Year {} {}", self.year, self.season)
+    }
+}
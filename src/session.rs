@@ -0,0 +1,183 @@
+use inquire::InquireError;
+
+use crate::{
+    all_buildings, all_service_buildings, all_species, all_themes, all_upgrades, clear_screen,
+    pause, prompt_text, Config, Economy, MenuKind, MultiSelectMenu, Profile, SingleSelectMenu,
+    Status,
+};
+
+/// Every settlement being planned, on the world map at the same time.
+#[derive(Debug)]
+pub struct Session {
+    pub config: Config,
+    pub settlements: Vec<Economy>,
+    pub current: usize,
+}
+
+impl Session {
+    pub fn new(config: Config) -> Self {
+        let mut session = Self {
+            config,
+            settlements: vec![],
+            current: 0,
+        };
+        session.add_settlement(String::from("Settlement 1"));
+        session
+    }
+
+    pub fn current(&mut self) -> &mut Economy {
+        &mut self.settlements[self.current]
+    }
+
+    /// Starts planning a new settlement and switches to it.
+    pub fn add_settlement(&mut self, name: String) {
+        let mut economy = Economy {
+            name,
+            ..Economy::default()
+        };
+        economy.configure(&self.config);
+        self.settlements.push(economy);
+        self.current = self.settlements.len() - 1;
+    }
+
+    pub fn plan(&mut self) -> Result<(), InquireError> {
+        match self.current().step()? {
+            Some(MenuKind::NewSettlement) => self.new_settlement()?,
+            Some(MenuKind::SwitchSettlement) => self.switch_settlement()?,
+            Some(MenuKind::CompareSettlements) => self.compare_settlements()?,
+            Some(MenuKind::EditProfile) => self.edit_profile()?,
+            Some(MenuKind::EditTheme) => self.edit_theme()?,
+            _ => {}
+        }
+        self.plan()
+    }
+
+    fn new_settlement(&mut self) -> Result<(), InquireError> {
+        let mut title = "What is the new settlement called?";
+        // settlements are told apart by name, so they need one of their own
+        let name = loop {
+            let name = prompt_text(title)?.trim().to_string();
+            if name.is_empty() {
+                title = "Settlements need a name, what is it called?";
+            } else if self.settlements.iter().any(|economy| economy.name == name) {
+                title = "There already is a settlement by that name, what is it called?";
+            } else {
+                break name;
+            }
+        };
+        self.add_settlement(name);
+        Ok(())
+    }
+
+    fn switch_settlement(&mut self) -> Result<(), InquireError> {
+        let names: Vec<String> = self
+            .settlements
+            .iter()
+            .enumerate()
+            .map(|(i, economy)| format!("{}. {}", i + 1, economy.name))
+            .collect();
+        let name = SingleSelectMenu::new(
+            "Which settlement do you want to plan?",
+            names.iter().map(String::as_str),
+        )
+        .interact()?;
+        if let Some(i) = names.iter().position(|known| known == name) {
+            self.current = i;
+        }
+        Ok(())
+    }
+
+    /// Unlocks are shared by every settlement, so the profile is edited for all of them.
+    fn edit_profile(&mut self) -> Result<(), InquireError> {
+        let mut species = MultiSelectMenu::new("Which species have you unlocked?", all_species());
+        species.select(self.config.profile.species.clone());
+        // a run always starts with some species and blueprints
+        loop {
+            species.interact()?;
+            if !species.view().is_empty {
+                break;
+            }
+        }
+        let mut buildings =
+            MultiSelectMenu::new("Which blueprints have you unlocked?", all_buildings());
+        buildings.select(self.config.profile.buildings.clone());
+        loop {
+            buildings.interact()?;
+            if !buildings.view().is_empty {
+                break;
+            }
+        }
+        let mut service_buildings = MultiSelectMenu::new(
+            "Which service building blueprints have you unlocked?",
+            all_service_buildings(),
+        );
+        service_buildings.select(self.config.profile.service_buildings.clone());
+        service_buildings.interact()?;
+        let mut upgrades =
+            MultiSelectMenu::new("Which upgrades have you unlocked?", all_upgrades());
+        upgrades.select(self.config.profile.upgrades.clone());
+        upgrades.interact()?;
+
+        self.config.profile = Profile {
+            species: species.get_selections(),
+            buildings: buildings.get_selections(),
+            service_buildings: service_buildings.get_selections(),
+            upgrades: upgrades.get_selections(),
+        };
+        for economy in &mut self.settlements {
+            economy.set_profile(self.config.profile.clone());
+        }
+        if let Err(e) = self.config.profile.save() {
+            println!("Couldn't save the profile: {e}");
+            return pause();
+        }
+        Ok(())
+    }
+
+    fn edit_theme(&mut self) -> Result<(), InquireError> {
+        self.config.theme =
+            SingleSelectMenu::new("Select a colour theme:", all_themes()).interact()?;
+        for economy in &mut self.settlements {
+            economy.theme = self.config.theme;
+        }
+        Ok(())
+    }
+
+    fn compare_settlements(&mut self) -> Result<(), InquireError> {
+        let theme = self.current().theme;
+        let mut needs: Vec<_> = self
+            .settlements
+            .iter()
+            .flat_map(|economy| economy.needs())
+            .collect();
+        needs.sort();
+        needs.dedup();
+        let met: Vec<_> = self
+            .settlements
+            .iter()
+            .map(|economy| (economy.name.as_str(), economy.needs(), economy.met_needs()))
+            .collect();
+
+        clear_screen();
+        println!("-----------------------");
+        println!("Needs covered per settlement");
+        println!("-----------------------");
+        for need in needs {
+            let coverage: Vec<String> = met
+                .iter()
+                .filter(|(_, needed, _)| needed.contains(&need))
+                .map(|(name, _, met)| {
+                    let status = if met.contains(&need) {
+                        Status::Available
+                    } else {
+                        Status::Missing
+                    };
+                    theme.paint(name, status)
+                })
+                .collect();
+            println!(" > {need}: {}", coverage.join(", "));
+        }
+        println!("-----------------------");
+        pause()
+    }
+}
@@ -0,0 +1,348 @@
+use std::io::{Error, ErrorKind};
+
+use crate::{resource::*, Availability, Resource, Service, Species};
+
+pub const PLAN_CODE_VERSION: u8 = 1;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const AVAILABILITY_BITS: u32 = 3;
+
+// The catalogs below fix the position of everything in a code. Only ever append to them, so
+// codes shared before something was added still decode to the same plan.
+
+fn species_catalog() -> Vec<Species> {
+    vec![
+        Species::Beavers,
+        Species::Humans,
+        Species::Harpies,
+        Species::Lizards,
+        Species::Foxes,
+    ]
+}
+
+fn service_catalog() -> Vec<Service> {
+    vec![
+        Service::Education,
+        Service::Religion,
+        Service::Treatment,
+        Service::Luxury,
+        Service::Leisure,
+        Service::Brawling,
+    ]
+}
+
+fn goods_catalog() -> Vec<Resource> {
+    vec![
+        oil(),
+        coal(),
+        sea_marrow(),
+        wood(),
+        pottery(),
+        waterskins(),
+        barrels(),
+        drizzle_water(),
+        storm_water(),
+        clearance_water(),
+        resin(),
+        leather(),
+        algae(),
+        plant_fiber(),
+        scales(),
+        reed(),
+        herbs(),
+        flour(),
+        grain(),
+        dye(),
+        copper_bars(),
+        crystallized_dew(),
+        stone(),
+        clay(),
+        salt(),
+        copper_ore(),
+        purging_fire(),
+        planks(),
+        fabric(),
+        bricks(),
+        parts(),
+        coats(),
+        boots(),
+        mushrooms(),
+        roots(),
+        vegetables(),
+        fish(),
+        meat(),
+        eggs(),
+        insects(),
+        berries(),
+        porridge(),
+        biscuits(),
+        pie(),
+        pickled_goods(),
+        jerky(),
+        paste(),
+        skewers(),
+        scrolls(),
+        incense(),
+        training_gear(),
+        wine(),
+        ale(),
+        tea(),
+    ]
+}
+
+fn availability_catalog() -> Vec<Availability> {
+    vec![
+        Availability::Unavailable,
+        Availability::Produced,
+        Availability::Traded,
+        Availability::Stockpiled,
+        Availability::Planned,
+        Availability::Depleted,
+    ]
+}
+
+/// Everything selected in a plan, small enough to paste into chat.
+#[derive(PartialEq, Debug, Default)]
+pub struct PlanCode {
+    pub species: Vec<Species>,
+    pub services: Vec<Service>,
+    /// Goods that aren't listed are unavailable.
+    pub goods: Vec<(Resource, Availability)>,
+}
+
+impl PlanCode {
+    /// The version, then every catalog as a count followed by a bit or availability per entry.
+    pub fn encode(&self) -> String {
+        let mut bits = BitWriter::default();
+        bits.push(PLAN_CODE_VERSION as u32, 8);
+
+        let species = species_catalog();
+        bits.push(species.len() as u32, 8);
+        for one_species in &species {
+            bits.push(self.species.contains(one_species) as u32, 1);
+        }
+
+        let services = service_catalog();
+        bits.push(services.len() as u32, 8);
+        for service in &services {
+            bits.push(self.services.contains(service) as u32, 1);
+        }
+
+        let goods = goods_catalog();
+        let availabilities = availability_catalog();
+        bits.push(goods.len() as u32, 8);
+        for good in &goods {
+            let availability = self
+                .goods
+                .iter()
+                .find(|(selected, _)| selected == good)
+                .map(|(_, availability)| *availability)
+                .unwrap_or(Availability::Unavailable);
+            let index = availabilities
+                .iter()
+                .position(|known| *known == availability)
+                .unwrap_or_default();
+            bits.push(index as u32, AVAILABILITY_BITS);
+        }
+
+        to_base64(&bits.bytes)
+    }
+
+    pub fn decode(code: &str) -> Result<Self, Error> {
+        let invalid = || {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("{code} is not a valid plan code"),
+            )
+        };
+        let sextets = code
+            .trim()
+            .bytes()
+            .map(|c| {
+                ALPHABET
+                    .iter()
+                    .position(|known| *known == c)
+                    .ok_or_else(invalid)
+            })
+            .collect::<Result<Vec<usize>, Error>>()?;
+        let bytes: Vec<u8> = sextets
+            .chunks(4)
+            .flat_map(|chunk| {
+                let group = chunk.iter().enumerate().fold(0u32, |group, (i, sextet)| {
+                    group | (*sextet as u32) << (18 - 6 * i)
+                });
+                (0..chunk.len().saturating_sub(1)).map(move |i| (group >> (16 - 8 * i)) as u8)
+            })
+            .collect();
+        let mut bits = BitReader { bytes, position: 0 };
+
+        let version = bits.read(8).ok_or_else(invalid)?;
+        if version == 0 || version > PLAN_CODE_VERSION as u32 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("plan code version {version} isn't supported, try updating"),
+            ));
+        }
+
+        let mut plan = Self::default();
+        // a code may come from before a catalog grew, so only read as many entries as it has
+        let species = species_catalog();
+        let count = bits.read(8).ok_or_else(invalid)? as usize;
+        for i in 0..count {
+            let selected = bits.read(1).ok_or_else(invalid)? == 1;
+            if let Some(one_species) = species.get(i).filter(|_| selected) {
+                plan.species.push(*one_species);
+            }
+        }
+
+        let services = service_catalog();
+        let count = bits.read(8).ok_or_else(invalid)? as usize;
+        for i in 0..count {
+            let selected = bits.read(1).ok_or_else(invalid)? == 1;
+            if let Some(service) = services.get(i).filter(|_| selected) {
+                plan.services.push(*service);
+            }
+        }
+
+        let goods = goods_catalog();
+        let availabilities = availability_catalog();
+        let count = bits.read(8).ok_or_else(invalid)? as usize;
+        for i in 0..count {
+            let index = bits.read(AVAILABILITY_BITS).ok_or_else(invalid)? as usize;
+            let availability = *availabilities.get(index).ok_or_else(invalid)?;
+            if let Some(good) = goods.get(i) {
+                if availability != Availability::Unavailable {
+                    plan.goods.push((*good, availability));
+                }
+            }
+        }
+
+        Ok(plan)
+    }
+}
+
+fn to_base64(bytes: &[u8]) -> String {
+    bytes
+        .chunks(3)
+        .flat_map(|chunk| {
+            let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+                group | (*byte as u32) << (16 - 8 * i)
+            });
+            (0..=chunk.len()).map(move |i| ALPHABET[(group >> (18 - 6 * i) & 63) as usize] as char)
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    length: usize,
+}
+
+impl BitWriter {
+    fn push(&mut self, value: u32, bits: u32) {
+        for bit in (0..bits).rev() {
+            if self.length.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if value >> bit & 1 == 1 {
+                if let Some(byte) = self.bytes.last_mut() {
+                    *byte |= 1 << (7 - self.length % 8);
+                }
+            }
+            self.length += 1;
+        }
+    }
+}
+
+struct BitReader {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl BitReader {
+    fn read(&mut self, bits: u32) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes.get(self.position / 8)?;
+            value = value << 1 | (*byte >> (7 - self.position % 8) & 1) as u32;
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{all_goods, all_services, all_species};
+
+    #[test]
+    fn round_trip() {
+        let plan = PlanCode {
+            species: vec![Species::Beavers, Species::Lizards],
+            services: vec![Service::Religion, Service::Brawling],
+            goods: vec![
+                (wood(), Availability::Produced),
+                (clay(), Availability::Depleted),
+                (planks(), Availability::Planned),
+                (porridge(), Availability::Traded),
+                (tea(), Availability::Stockpiled),
+            ],
+        };
+        assert_eq!(PlanCode::decode(&plan.encode()).unwrap(), plan);
+        assert_eq!(
+            PlanCode::decode(&PlanCode::default().encode()).unwrap(),
+            PlanCode::default()
+        );
+    }
+
+    #[test]
+    fn decodes_codes_from_smaller_catalogs() {
+        let mut bits = BitWriter::default();
+        bits.push(PLAN_CODE_VERSION as u32, 8);
+        bits.push(2, 8);
+        bits.push(0, 1);
+        bits.push(1, 1);
+        bits.push(1, 8);
+        bits.push(1, 1);
+        bits.push(2, 8);
+        bits.push(1, AVAILABILITY_BITS);
+        bits.push(0, AVAILABILITY_BITS);
+
+        let plan = PlanCode::decode(&to_base64(&bits.bytes)).unwrap();
+        assert_eq!(plan.species, vec![species_catalog()[1]]);
+        assert_eq!(plan.services, vec![service_catalog()[0]]);
+        assert_eq!(
+            plan.goods,
+            vec![(goods_catalog()[0], Availability::Produced)]
+        );
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(PlanCode::decode("not a plan code!").is_err());
+        assert!(PlanCode::decode("").is_err());
+        assert!(PlanCode::decode(&to_base64(&[PLAN_CODE_VERSION + 1])).is_err());
+    }
+
+    #[test]
+    fn catalogs_cover_everything_once() {
+        fn covers<T: PartialEq + std::fmt::Debug>(catalog: Vec<T>, everything: Vec<T>) {
+            assert!(catalog.len() < 256);
+            for (i, entry) in catalog.iter().enumerate() {
+                assert!(!catalog[..i].contains(entry), "{entry:?} is listed twice");
+            }
+            for entry in &everything {
+                assert!(
+                    catalog.contains(entry),
+                    "{entry:?} is missing from the catalog"
+                );
+            }
+        }
+        covers(species_catalog(), all_species());
+        covers(service_catalog(), all_services());
+        covers(goods_catalog(), all_goods());
+        assert!(availability_catalog().len() <= 1 << AVAILABILITY_BITS);
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::{self, Display};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{pascalize, titleize, Clothing, ComplexFood, Need, Service};
+use crate::{pascalize, titleize, Clothing, ComplexFood, Housing, Need, Service, Specialization};
 
 pub fn all_species() -> Vec<Species> {
     vec![
@@ -25,6 +25,16 @@ pub enum Species {
 }
 
 impl Species {
+    pub fn specializations(&self) -> Vec<Specialization> {
+        match self {
+            Self::Beavers => vec![Specialization::Woodworking, Specialization::Brewing],
+            Self::Humans => vec![Specialization::Farming, Specialization::Cooking],
+            Self::Lizards => vec![Specialization::Meat, Specialization::Warmth],
+            Self::Harpies => vec![Specialization::Alchemy, Specialization::Tailoring],
+            Self::Foxes => vec![Specialization::Gathering, Specialization::Woodworking],
+        }
+    }
+
     pub fn needs(&self) -> Vec<Need> {
         match self {
             Self::Beavers => {
@@ -34,6 +44,7 @@ impl Species {
                     Need::Service(Service::Education),
                     Need::Service(Service::Luxury),
                     Need::Clothing(Clothing::Coats),
+                    Need::Housing(Housing::BeaverHouse),
                 ]
             }
             Self::Harpies => {
@@ -44,6 +55,7 @@ impl Species {
                     Need::Service(Service::Treatment),
                     Need::Clothing(Clothing::Coats),
                     Need::Clothing(Clothing::Boots),
+                    Need::Housing(Housing::HarpyHouse),
                 ]
             }
             Self::Humans => {
@@ -54,6 +66,7 @@ impl Species {
                     Need::Service(Service::Religion),
                     Need::Service(Service::Leisure),
                     Need::Clothing(Clothing::Coats),
+                    Need::Housing(Housing::HumanHouse),
                 ]
             }
             Self::Lizards => {
@@ -64,6 +77,7 @@ impl Species {
                     Need::ComplexFood(ComplexFood::Skewers),
                     Need::Service(Service::Brawling),
                     Need::Clothing(Clothing::Boots),
+                    Need::Housing(Housing::LizardHouse),
                 ]
             }
             Self::Foxes => {
@@ -74,6 +88,7 @@ impl Species {
                     Need::Clothing(Clothing::Boots),
                     Need::Service(Service::Religion),
                     Need::Service(Service::Treatment),
+                    Need::Housing(Housing::FoxHouse),
                 ]
             } // Self::Frogs => {
               //     vec![
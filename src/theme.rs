@@ -0,0 +1,139 @@
+use crossterm::style::{Color, ContentStyle, Stylize};
+
+use std::{
+    fmt::{self, Display},
+    io::{stdout, IsTerminal},
+};
+
+use crate::{pascalize, titleize};
+
+pub fn all_themes() -> Vec<Theme> {
+    vec![
+        Theme::Classic,
+        Theme::Deuteranopia,
+        Theme::HighContrast,
+        Theme::Monochrome,
+        Theme::Plain,
+    ]
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Theme {
+    #[default]
+    Classic,
+    Deuteranopia,
+    HighContrast,
+    Monochrome,
+    Plain,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Status {
+    Available,
+    Stockpiled,
+    Planned,
+    Chain,
+    Depleted,
+    Missing,
+}
+
+impl Status {
+    pub fn marker(&self) -> &'static str {
+        match self {
+            Self::Available => "[have]",
+            Self::Stockpiled => "[stock]",
+            Self::Planned => "[planned]",
+            Self::Chain => "[chain]",
+            Self::Depleted => "[depleted]",
+            Self::Missing => "[need]",
+        }
+    }
+
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Self::Available => "✓",
+            Self::Stockpiled => "◒",
+            Self::Planned => "…",
+            Self::Chain => "~",
+            Self::Depleted => "⊘",
+            Self::Missing => "✗",
+        }
+    }
+}
+
+impl Theme {
+    /// Picks the plain theme when colour would be lost or unwanted, see <https://no-color.org>.
+    pub fn detect() -> Self {
+        let no_color = std::env::var("NO_COLOR").is_ok_and(|value| !value.is_empty());
+        if no_color || !stdout().is_terminal() {
+            Self::Plain
+        } else {
+            Self::default()
+        }
+    }
+
+    pub fn paint(&self, facet: impl Display, status: Status) -> String {
+        match self {
+            Self::Monochrome => format!("{} {facet}", status.glyph()),
+            Self::Plain => format!("{facet} {}", status.marker()),
+            _ => self.style(status).apply(facet).to_string(),
+        }
+    }
+
+    fn style(&self, status: Status) -> ContentStyle {
+        let style = ContentStyle::new();
+        match (self, status) {
+            (Self::Classic, Status::Available) => style.with(Color::Green),
+            (Self::Classic, Status::Stockpiled) => style.with(Color::Cyan),
+            (Self::Classic, Status::Planned) => style.with(Color::Magenta),
+            (Self::Classic, Status::Chain) => style.with(Color::Yellow),
+            (Self::Classic, Status::Depleted) => style.with(Color::Red).crossed_out(),
+            (Self::Classic, Status::Missing) => style.with(Color::Red),
+            // blue and orange from the okabe-ito palette stay distinct for red-green colour blindness
+            (Self::Deuteranopia, Status::Available) => style.with(Color::AnsiValue(33)),
+            (Self::Deuteranopia, Status::Stockpiled) => style.with(Color::AnsiValue(117)),
+            (Self::Deuteranopia, Status::Planned) => style.with(Color::AnsiValue(175)),
+            (Self::Deuteranopia, Status::Chain) => style.with(Color::AnsiValue(220)),
+            (Self::Deuteranopia, Status::Depleted) => {
+                style.with(Color::AnsiValue(208)).crossed_out()
+            }
+            (Self::Deuteranopia, Status::Missing) => style.with(Color::AnsiValue(208)),
+            (Self::HighContrast, Status::Available) => style.with(Color::White).bold(),
+            (Self::HighContrast, Status::Stockpiled) => style.with(Color::White).italic(),
+            (Self::HighContrast, Status::Planned) => style.with(Color::White).dim(),
+            (Self::HighContrast, Status::Chain) => style.with(Color::White).underlined(),
+            (Self::HighContrast, Status::Depleted) => {
+                style.with(Color::Black).on(Color::Yellow).crossed_out()
+            }
+            (Self::HighContrast, Status::Missing) => {
+                style.with(Color::Black).on(Color::Yellow).bold()
+            }
+            (Self::Monochrome | Self::Plain, _) => style,
+        }
+    }
+}
+
+impl TryFrom<&String> for Theme {
+    type Error = std::io::Error;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        let result = all_themes()
+            .into_iter()
+            .find(|theme| pascalize(theme) == pascalize(value));
+
+        if let Some(theme) = result {
+            Ok(theme)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{value} is not a valid theme"),
+            ))
+        }
+    }
+}
+
+impl Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use crate::{Availability, Building, Moment, Need, Species};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Milestone {
+    /// A good's availability changed, e.g. its blueprint was picked or it was bought.
+    Selected(String, Availability),
+    Removed(String),
+    Joined(Species),
+    Left(Species),
+    Blueprint(Building),
+    /// A building was taken out of the build queue.
+    Dropped(Building),
+    Satisfied(Need),
+}
+
+/// Every change to the settlement, stamped with the year and season it happened in.
+#[derive(Debug, Default)]
+pub struct Timeline {
+    pub entries: Vec<(Moment, Milestone)>,
+    facets: BTreeMap<String, Availability>,
+    species: Vec<Species>,
+    blueprints: Vec<Building>,
+    satisfied: Vec<Need>,
+}
+
+impl Timeline {
+    /// Records whatever changed since the last update.
+    pub fn update(
+        &mut self,
+        moment: Moment,
+        facets: BTreeMap<String, Availability>,
+        species: Vec<Species>,
+        blueprints: Vec<Building>,
+        met: &[Need],
+    ) {
+        for (facet, availability) in &facets {
+            if self.facets.get(facet) != Some(availability) {
+                self.entries.push((
+                    moment,
+                    Milestone::Selected(facet.to_string(), *availability),
+                ));
+            }
+        }
+        for facet in self.facets.keys() {
+            if !facets.contains_key(facet) {
+                self.entries
+                    .push((moment, Milestone::Removed(facet.to_string())));
+            }
+        }
+        for joined in species
+            .iter()
+            .filter(|joined| !self.species.contains(joined))
+        {
+            self.entries.push((moment, Milestone::Joined(*joined)));
+        }
+        for left in self.species.iter().filter(|left| !species.contains(left)) {
+            self.entries.push((moment, Milestone::Left(*left)));
+        }
+        for building in &blueprints {
+            if !self.blueprints.contains(building) {
+                self.entries.push((moment, Milestone::Blueprint(*building)));
+            }
+        }
+        for building in &self.blueprints {
+            if !blueprints.contains(building) {
+                self.entries.push((moment, Milestone::Dropped(*building)));
+            }
+        }
+        for need in met {
+            if !self.satisfied.contains(need) {
+                self.satisfied.push(*need);
+                self.entries.push((moment, Milestone::Satisfied(*need)));
+            }
+        }
+        self.facets = facets;
+        self.species = species;
+        self.blueprints = blueprints;
+    }
+
+    /// The moment each need was first met, in order.
+    pub fn satisfied(&self) -> Vec<(Moment, Need)> {
+        self.entries
+            .iter()
+            .filter_map(|(moment, milestone)| match milestone {
+                Milestone::Satisfied(need) => Some((*moment, *need)),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use crate::{Modifiers, Need, Recipe, Resource};
+
+#[derive(Debug, Default)]
+pub struct TradeAdvice {
+    /// Goods worth buying, with how many unmet needs and ingredient slots each would unlock.
+    pub buys: Vec<(Resource, usize)>,
+    /// Surplus that none of the current needs call for, most valuable first.
+    pub sells: Vec<Resource>,
+}
+
+pub fn advise_trade(
+    needs: &[Need],
+    available: &[Resource],
+    trader_stock: &[Resource],
+    surplus: &[Resource],
+    modifiers: &Modifiers,
+) -> TradeAdvice {
+    let mut buys: Vec<(Resource, usize)> = trader_stock
+        .iter()
+        .filter(|good| !available.contains(good))
+        .map(|good| (*good, unlocks(*good, needs, available, modifiers)))
+        .filter(|(_, unlocked)| *unlocked > 0)
+        .collect();
+    // most useful first, the cheaper of two equally useful goods wins
+    buys.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.0.trade_value().total_cmp(&b.0.trade_value()))
+    });
+
+    let mut sells: Vec<Resource> = surplus
+        .iter()
+        .filter(|good| !needs.iter().any(|need| uses(need, **good, modifiers)))
+        .copied()
+        .collect();
+    sells.sort_by(|a, b| b.trade_value().total_cmp(&a.trade_value()));
+
+    TradeAdvice { buys, sells }
+}
+
+fn unlocks(good: Resource, needs: &[Need], available: &[Resource], modifiers: &Modifiers) -> usize {
+    needs
+        .iter()
+        .filter(|need| {
+            !modifiers
+                .goods(need)
+                .iter()
+                .any(|ready| available.contains(ready))
+        })
+        .map(|need| {
+            let direct = usize::from(modifiers.goods(need).contains(&good));
+            let slots = modifiers
+                .recipe(need)
+                .iter()
+                .filter(|ingredient_slot| {
+                    ingredient_slot.contains(&good)
+                        && !ingredient_slot
+                            .iter()
+                            .any(|ingredient| available.contains(ingredient))
+                })
+                .count();
+            direct + slots
+        })
+        .sum()
+}
+
+fn uses(need: &Need, good: Resource, modifiers: &Modifiers) -> bool {
+    modifiers.goods(need).contains(&good) || in_chain(need, good, modifiers)
+}
+
+// anywhere down the recipe tree, e.g. grain for the flour in biscuits.
+fn in_chain(product: &(impl Recipe + Display), good: Resource, modifiers: &Modifiers) -> bool {
+    modifiers
+        .recipe(product)
+        .concat()
+        .iter()
+        .any(|ingredient| *ingredient == good || in_chain(ingredient, good, modifiers))
+}
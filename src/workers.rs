@@ -0,0 +1,143 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use crate::{all_species, titleize, Building, Species};
+
+pub const WORKPLACES_PER_BUILDING: u32 = 2;
+
+/// The kind of work a species is good at. Working a matching building raises their resolve.
+#[derive(Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Specialization {
+    Alchemy,
+    Brewing,
+    Cooking,
+    Farming,
+    Gathering,
+    Meat,
+    Tailoring,
+    Warmth,
+    Woodworking,
+}
+
+impl Display for Specialization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", titleize(self))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Assignment {
+    pub building: Building,
+    pub species: Species,
+    pub workers: u32,
+    pub specialized: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Workforce {
+    pub population: BTreeMap<Species, u32>,
+}
+
+impl Workforce {
+    pub fn population_of(&self, species: Species) -> u32 {
+        self.population.get(&species).copied().unwrap_or_default()
+    }
+
+    /// Staffs every workplace, specialists first. Buildings that few of your species specialise in
+    /// are filled before the ones many could take, then leftover villagers fill the gaps.
+    pub fn allocate(&self, buildings: &BTreeMap<Building, u32>) -> Vec<Assignment> {
+        let mut free = self.population.clone();
+        let mut slots: Vec<(Building, u32)> = buildings
+            .iter()
+            .map(|(building, count)| (*building, count * WORKPLACES_PER_BUILDING))
+            .collect();
+        slots.sort_by_key(|(building, _)| self.specialists(*building).len());
+
+        let mut assignments = Vec::new();
+        for (building, open) in slots.iter_mut() {
+            while *open > 0 {
+                let Some((species, workers)) = self
+                    .specialists(*building)
+                    .into_iter()
+                    .map(|species| (species, free.get(&species).copied().unwrap_or_default()))
+                    .filter(|(_, workers)| *workers > 0)
+                    .max_by_key(|(_, workers)| *workers)
+                else {
+                    break;
+                };
+                let staffed = workers.min(*open);
+                *open -= staffed;
+                free.insert(species, workers - staffed);
+                assign(&mut assignments, *building, species, staffed, true);
+            }
+        }
+        for (building, open) in slots.iter_mut() {
+            while *open > 0 {
+                let Some((species, workers)) = free
+                    .iter()
+                    .map(|(species, workers)| (*species, *workers))
+                    .filter(|(_, workers)| *workers > 0)
+                    .max_by_key(|(_, workers)| *workers)
+                else {
+                    break;
+                };
+                let staffed = workers.min(*open);
+                *open -= staffed;
+                free.insert(species, workers - staffed);
+                assign(&mut assignments, *building, species, staffed, false);
+            }
+        }
+        assignments
+    }
+
+    /// Villagers left without a workplace after [`Workforce::allocate`].
+    pub fn idle(&self, assignments: &[Assignment]) -> Vec<(Species, u32)> {
+        self.population
+            .iter()
+            .map(|(species, population)| {
+                let working: u32 = assignments
+                    .iter()
+                    .filter(|assignment| assignment.species == *species)
+                    .map(|assignment| assignment.workers)
+                    .sum();
+                (*species, population.saturating_sub(working))
+            })
+            .filter(|(_, idle)| *idle > 0)
+            .collect()
+    }
+
+    fn specialists(&self, building: Building) -> Vec<Species> {
+        all_species()
+            .into_iter()
+            .filter(|species| self.population_of(*species) > 0)
+            .filter(|species| {
+                species
+                    .specializations()
+                    .contains(&building.specialization())
+            })
+            .collect()
+    }
+}
+
+fn assign(
+    assignments: &mut Vec<Assignment>,
+    building: Building,
+    species: Species,
+    workers: u32,
+    specialized: bool,
+) {
+    match assignments
+        .iter_mut()
+        .find(|assignment| assignment.building == building && assignment.species == species)
+    {
+        Some(assignment) => assignment.workers += workers,
+        None => assignments.push(Assignment {
+            building,
+            species,
+            workers,
+            specialized,
+        }),
+    }
+}